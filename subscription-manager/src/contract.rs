@@ -1,22 +1,76 @@
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
 use cosmwasm_std::{
-    entry_point, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult
+    attr, entry_point, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Timestamp,
 };
-use secret_toolkit::permit::{validate, Permit};
+use std::collections::HashSet;
+use hkdf::Hkdf;
+use secret_toolkit::permit::{validate, Permit, RevokedPermits};
+use secret_toolkit::utils::{pad_handle_result, pad_query_result};
 use sha2::{Digest, Sha256};
-use crate::msg::{ApiKeyResponse, ExecuteMsg, GetApiKeysResponse, InstantiateMsg, MigrateMsg, QueryMsg, SubscriberStatusResponse};
-use crate::state::{config, config_read, ApiKey, State, Subscriber, API_KEY_MAP, SB_MAP};
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+use crate::msg::{
+    AllOperatorsResponse, ApiKeyResponse, CreateViewingKeyResponse, EciesApiKey, ExecuteMsg,
+    GetApiKeysResponse, InstantiateMsg, ListScheduledResponse, MigrateMsg, MyApiKeysResponse,
+    OperatorEntry, QueryMsg, ScheduledOperationResponse, SubscriberInfo, SubscriberStatusResponse,
+    SubscribersResponse,
+};
+use crate::state::{
+    api_key_secret, api_key_secret_read, config, config_read, contract_version,
+    contract_version_read, min_delay, min_delay_read, pending_admin, pending_admin_read, ApiKey,
+    Expiration, OperatorPermissions, ScheduledOperation, State, Subscriber, API_KEY_MAP,
+    ECIES_PUBKEY_MAP, PERMISSIONS, SB_MAP, SCHEDULED_OPS, VIEWING_KEY_MAP,
+};
+
+const PERMITS_API_KEYS_PREFIX: &str = "permits_api_keys";
+const PERMITS_SUBSCRIBER_STATUS_PREFIX: &str = "permits_subscriber_status";
+const PERMITS_MY_API_KEYS_PREFIX: &str = "permits_my_api_keys";
+const PERMITS_LIST_SUBSCRIBERS_PREFIX: &str = "permits_list_subscribers";
+
+const DEFAULT_SUBSCRIBERS_LIMIT: u32 = 30;
+const MAX_SUBSCRIBERS_LIMIT: u32 = 100;
+
+/// Domain-separation labels for the two independent HKDF-SHA256 derivations:
+/// one for the at-rest encryption key, one for each ECIES delivery key.
+const AT_REST_HKDF_INFO: &[u8] = b"subscription-manager/api-key-at-rest";
+const ECIES_HKDF_INFO: &[u8] = b"subscription-manager/ecies-delivery";
+
+/// Query and execute responses are padded so their serialized (and thus
+/// ciphertext) length always rounds up to a multiple of this many bytes.
+/// Secret Network encrypts responses, but ciphertext length still leaks
+/// through the block explorer/API. Padding hides size differences that fall
+/// within one block (e.g. an active vs. an inactive subscriber); a response
+/// that spans more blocks, such as a much longer API key list, still shows
+/// a larger size.
+pub const BLOCK_SIZE: usize = 256;
+
+/// Upper bound on the number of keys a single `BatchRegisterSubscribers` or
+/// `BatchRemoveSubscribers` call may touch, so a batch can't be used to run a
+/// single execution out of gas or make it prohibitively large to simulate.
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// Schema version of the data this version of the contract expects. Bumped
+/// whenever `migrate` needs to run a new data transformation; `migrate`
+/// refuses to run if the stored version is already ahead of this.
+pub const CONTRACT_VERSION: u64 = 1;
+
+/// How long past `eta` a scheduled operation may still be run via
+/// `ExecuteScheduled` before it's considered stale and must be cancelled and
+/// rescheduled, mirroring Compound's `Timelock` grace period.
+pub const GRACE_PERIOD_SECONDS: u64 = 14 * 24 * 60 * 60;
 
 // Entry point for contract initialization
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> StdResult<Response> {
     // Set the admin to the sender who initializes the contract
     let state = State {
-        admin: info.sender.clone(),
+        admins: vec![info.sender.clone()],
     };
 
     // Log a debug message
@@ -26,6 +80,18 @@ pub fn instantiate(
     // Save the initial state
     config(deps.storage).save(&state)?;
 
+    // Seed the per-contract secret used to encrypt stored API keys at rest.
+    // Falls back to the contract address if the chain doesn't supply
+    // on-chain randomness at instantiation time.
+    let secret = match &env.block.random {
+        Some(random) => random.0.clone(),
+        None => Sha256::digest(env.contract.address.as_bytes()).to_vec(),
+    };
+    api_key_secret(deps.storage).save(&secret)?;
+    contract_version(deps.storage).save(&CONTRACT_VERSION)?;
+    pending_admin(deps.storage).save(&None)?;
+    min_delay(deps.storage).save(&msg.min_delay.unwrap_or(0))?;
+
     Ok(Response::default())
 }
 
@@ -33,22 +99,107 @@ pub fn instantiate(
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> StdResult<Response> {
-    match msg {
+    let response = match msg {
         // Handle registration of a subscriber
-        ExecuteMsg::RegisterSubscriber { public_key } => try_register_subscriber(deps, info, public_key),
+        ExecuteMsg::RegisterSubscriber { public_key, expires } => {
+            try_register_subscriber(deps, info, public_key, expires)
+        }
         // Handle removal of a subscriber
         ExecuteMsg::RemoveSubscriber { public_key } => try_remove_subscriber(deps, info, public_key),
-        // Handle setting a new admin
+        // Handle extending or shortening a subscriber's expiration
+        ExecuteMsg::RenewSubscriber { public_key, expires } => {
+            try_renew_subscriber(deps, info, public_key, expires)
+        }
+        // Handle registering many subscribers in one transaction
+        ExecuteMsg::BatchRegisterSubscribers { public_keys } => {
+            try_batch_register_subscribers(deps, info, public_keys)
+        }
+        // Handle removing many subscribers in one transaction
+        ExecuteMsg::BatchRemoveSubscribers { public_keys } => {
+            try_batch_remove_subscribers(deps, info, public_keys)
+        }
+        // Handle setting a new admin (compatibility shim for AddAdmins)
         ExecuteMsg::SetAdmin { public_key } => try_set_admin(deps, info, public_key),
+        // Handle granting admin rights to one or more addresses
+        ExecuteMsg::AddAdmins { public_keys } => try_add_admins(deps, info, public_keys),
+        // Handle an admin removing itself from the admin set
+        ExecuteMsg::Leave {} => try_leave(deps, info),
         // Handle adding an API key
         ExecuteMsg::AddApiKey { api_key } => try_add_api_key(deps, info, api_key),
         // Handle revoking an API key
         ExecuteMsg::RevokeApiKey { api_key } => try_revoke_api_key(deps, info, api_key),
-    }
+        // Handle revoking a previously signed permit
+        ExecuteMsg::RevokePermit { permit_name } => try_revoke_permit(deps, info, permit_name),
+        // Handle overwriting the caller's viewing key with a caller-supplied one
+        ExecuteMsg::SetViewingKey { key } => try_set_viewing_key(deps, info, key),
+        // Handle generating a fresh viewing key for the caller
+        ExecuteMsg::CreateViewingKey { entropy } => try_create_viewing_key(deps, env, info, entropy),
+        // Handle a subscriber setting their ECIES delivery key
+        ExecuteMsg::SetEciesPubkey { pubkey } => try_set_ecies_pubkey(deps, info, pubkey),
+        // Handle an admin designating a pending admin candidate
+        ExecuteMsg::TransferAdmin { public_key } => try_transfer_admin(deps, info, public_key),
+        // Handle the pending candidate accepting the admin handover
+        ExecuteMsg::AcceptAdmin {} => try_accept_admin(deps, info),
+        // Handle granting scoped permissions to an operator
+        ExecuteMsg::GrantOperator { public_key, permissions } => {
+            try_grant_operator(deps, info, public_key, permissions)
+        }
+        // Handle revoking an operator's permissions
+        ExecuteMsg::RevokeOperator { public_key } => try_revoke_operator(deps, info, public_key),
+        // Handle queuing a sensitive operation behind a timelock
+        ExecuteMsg::ScheduleOperation { id, operation, eta } => {
+            try_schedule_operation(deps, env, info, id, operation, eta)
+        }
+        // Handle running a previously scheduled operation once its eta has passed
+        ExecuteMsg::ExecuteScheduled { id } => try_execute_scheduled(deps, env, id),
+        // Handle dropping a scheduled operation without running it
+        ExecuteMsg::CancelScheduled { id } => try_cancel_scheduled(deps, info, id),
+    };
+    pad_handle_result(response, BLOCK_SIZE)
+}
+
+/// Revokes `permit_name` for the calling address, mirroring SNIP-721's
+/// `RevokedPermits`. Revocation is idempotent: revoking an already-revoked
+/// name is a no-op rather than an error. A permit name isn't scoped to one
+/// query, so we revoke it under every storage prefix `validate` is called
+/// with.
+pub fn try_revoke_permit(
+    deps: DepsMut,
+    info: MessageInfo,
+    permit_name: String,
+) -> StdResult<Response> {
+    RevokedPermits::revoke_permit(
+        deps.storage,
+        PERMITS_API_KEYS_PREFIX,
+        info.sender.as_str(),
+        &permit_name,
+    );
+    RevokedPermits::revoke_permit(
+        deps.storage,
+        PERMITS_SUBSCRIBER_STATUS_PREFIX,
+        info.sender.as_str(),
+        &permit_name,
+    );
+    RevokedPermits::revoke_permit(
+        deps.storage,
+        PERMITS_MY_API_KEYS_PREFIX,
+        info.sender.as_str(),
+        &permit_name,
+    );
+    RevokedPermits::revoke_permit(
+        deps.storage,
+        PERMITS_LIST_SUBSCRIBERS_PREFIX,
+        info.sender.as_str(),
+        &permit_name,
+    );
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_permit")
+        .add_attribute("permit_name", permit_name))
 }
 
 pub fn try_add_api_key(
@@ -59,8 +210,8 @@ pub fn try_add_api_key(
     let config = config_read(deps.storage);
     let state = config.load()?;
 
-    // Check if the sender is the admin
-    if info.sender != state.admin {
+    // Check if the sender is an admin
+    if !state.is_admin(&info.sender) {
         return Err(StdError::generic_err("Only admin can add API keys"));
     }
 
@@ -75,8 +226,10 @@ pub fn try_add_api_key(
         return Err(StdError::generic_err("API key (hash) already exists"));
     }
 
-    // 3. Insert the hash into the map
-    let api_key_data = ApiKey {};
+    // 3. Encrypt the plaintext key at rest and insert it under its hash
+    let secret = api_key_secret_read(deps.storage).load()?;
+    let (nonce, ciphertext) = encrypt_at_rest(&secret, &key_hash, api_key.as_bytes())?;
+    let api_key_data = ApiKey { nonce, ciphertext };
     API_KEY_MAP
         .insert(deps.storage, &key_hash, &api_key_data)
         .map_err(|err| StdError::generic_err(err.to_string()))?;
@@ -88,6 +241,62 @@ pub fn try_add_api_key(
         .add_attribute("stored_hash", key_hash))
 }
 
+/// Overwrites the caller's viewing key with a caller-supplied `key`,
+/// mirroring SNIP-20's `SetViewingKey`. Only the hash of the key is stored,
+/// never the key itself.
+pub fn try_set_viewing_key(deps: DepsMut, info: MessageInfo, key: String) -> StdResult<Response> {
+    let key_hash: [u8; 32] = Sha256::digest(key.as_bytes()).into();
+    VIEWING_KEY_MAP
+        .insert(deps.storage, &info.sender.to_string(), &key_hash)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new().add_attribute("action", "set_viewing_key"))
+}
+
+/// Derives a fresh viewing key for the caller from `entropy`, on-chain
+/// randomness and the caller's address, stores its hash, and returns the key
+/// itself in the response `data` so the caller can retrieve it.
+pub fn try_create_viewing_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entropy: String,
+) -> StdResult<Response> {
+    let randomness = match &env.block.random {
+        Some(random) => random.0.clone(),
+        None => Sha256::digest(env.contract.address.as_bytes()).to_vec(),
+    };
+    let mut preimage = randomness;
+    preimage.extend_from_slice(info.sender.as_bytes());
+    preimage.extend_from_slice(entropy.as_bytes());
+    let key = hex::encode(Sha256::digest(&preimage));
+
+    let key_hash: [u8; 32] = Sha256::digest(key.as_bytes()).into();
+    VIEWING_KEY_MAP
+        .insert(deps.storage, &info.sender.to_string(), &key_hash)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_viewing_key")
+        .set_data(to_binary(&CreateViewingKeyResponse { key })?))
+}
+
+/// Sets the caller's ECIES delivery key from a hex-encoded 32-byte x25519
+/// public key, overwriting any previously set key.
+pub fn try_set_ecies_pubkey(deps: DepsMut, info: MessageInfo, pubkey: String) -> StdResult<Response> {
+    let bytes = hex::decode(&pubkey)
+        .map_err(|_| StdError::generic_err("pubkey must be hex-encoded"))?;
+    let pubkey_bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| StdError::generic_err("pubkey must be a 32-byte x25519 public key"))?;
+
+    ECIES_PUBKEY_MAP
+        .insert(deps.storage, &info.sender.to_string(), &pubkey_bytes)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new().add_attribute("action", "set_ecies_pubkey"))
+}
+
 pub fn try_revoke_api_key(
     deps: DepsMut,
     info: MessageInfo,
@@ -96,8 +305,8 @@ pub fn try_revoke_api_key(
     let config = config_read(deps.storage);
     let state = config.load()?;
 
-    // Check if the sender is the admin
-    if info.sender != state.admin {
+    // Check if the sender is an admin
+    if !state.is_admin(&info.sender) {
         return Err(StdError::generic_err("Only admin can revoke API keys"));
     }
 
@@ -126,6 +335,30 @@ pub fn try_revoke_api_key(
 pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     match msg {
         MigrateMsg::Migrate {} => {
+            // Contracts deployed before versioning was introduced have no
+            // stored version; treat that as version 0 rather than erroring.
+            let stored_version = contract_version_read(deps.storage).load().unwrap_or(0);
+
+            if stored_version > CONTRACT_VERSION {
+                return Err(StdError::generic_err(format!(
+                    "Cannot downgrade contract from version {} to {}",
+                    stored_version, CONTRACT_VERSION
+                )));
+            }
+
+            // No data transformations are defined between version 0 and
+            // CONTRACT_VERSION yet: `API_KEY_MAP`/`SB_MAP` are left as-is.
+            // Future version bumps add transformation steps here, gated on
+            // `stored_version`, before the version is advanced below.
+
+            contract_version(deps.storage).save(&CONTRACT_VERSION)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "migrate")
+                .add_attribute("from_version", stored_version.to_string())
+                .add_attribute("to_version", CONTRACT_VERSION.to_string()))
+        }
+        MigrateMsg::ClearApiKeys {} => {
             // Collect all keys using `iter_keys`
             let keys_to_remove: Vec<String> = API_KEY_MAP
                 .iter_keys(deps.storage)?
@@ -138,24 +371,215 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
             }
 
             Ok(Response::new()
-                .add_attribute("action", "migrate")
+                .add_attribute("action", "clear_api_keys")
                 .add_attribute("status", "api_key_map_cleared"))
         }
         MigrateMsg::StdError {} => Err(StdError::generic_err("this is an std error")),
     }
 }
 
+/// Checks that `sender` has been granted `can_register`, and if its budget
+/// is capped (`remaining_registrations` is `Some`), spends one unit of it.
+/// Errors if `sender` has no granted permissions at all, lacks
+/// `can_register`, or has exhausted its budget.
+fn spend_registration_budget(storage: &mut dyn cosmwasm_std::Storage, sender: &Addr) -> StdResult<()> {
+    let mut permissions = PERMISSIONS
+        .get(storage, &sender.to_string())
+        .ok_or_else(|| StdError::generic_err("Only admin can register subscribers"))?;
+
+    if !permissions.can_register {
+        return Err(StdError::generic_err("Operator is not permitted to register subscribers"));
+    }
+
+    if let Some(remaining) = permissions.remaining_registrations {
+        if remaining == 0 {
+            return Err(StdError::generic_err("Operator has no remaining registration budget"));
+        }
+        permissions.remaining_registrations = Some(remaining - 1);
+        PERMISSIONS
+            .insert(storage, &sender.to_string(), &permissions)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Admin-only. Grants `public_key` the given scoped permissions, overwriting
+/// any it was previously granted.
+pub fn try_grant_operator(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_key: String,
+    permissions: OperatorPermissions,
+) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can grant operator permissions"));
+    }
+
+    let addr = deps.api.addr_validate(&public_key).map_err(|err| {
+        StdError::generic_err(format!("Invalid address: {}", err))
+    })?;
+    PERMISSIONS
+        .insert(deps.storage, &addr.to_string(), &permissions)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_operator")
+        .add_attribute("operator", public_key))
+}
+
+/// Admin-only. Revokes all permissions previously granted to `public_key`.
+pub fn try_revoke_operator(deps: DepsMut, info: MessageInfo, public_key: String) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can revoke operator permissions"));
+    }
+
+    if !PERMISSIONS.contains(deps.storage, &public_key) {
+        return Err(StdError::generic_err("Operator not found"));
+    }
+    PERMISSIONS
+        .remove(deps.storage, &public_key)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_operator")
+        .add_attribute("operator", public_key))
+}
+
+/// Admin-only. Queues `operation` under `id`, to become runnable via
+/// `ExecuteScheduled` once `eta` passes. Rejects `eta` that doesn't clear the
+/// instantiate-configured `min_delay`, and rejects reusing an `id` that
+/// already has an operation pending.
+pub fn try_schedule_operation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    operation: Box<ExecuteMsg>,
+    eta: Timestamp,
+) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can schedule operations"));
+    }
+
+    if SCHEDULED_OPS.contains(deps.storage, &id) {
+        return Err(StdError::generic_err(format!(
+            "An operation is already scheduled under id \"{}\"",
+            id
+        )));
+    }
+
+    // ScheduledOperation/ListScheduled return the pending operation verbatim
+    // with no redaction, so a variant carrying a raw secret would publish it
+    // in plaintext to anyone querying the timelock for the whole min_delay
+    // window. Refuse to schedule those; they can still be executed directly.
+    match operation.as_ref() {
+        ExecuteMsg::AddApiKey { .. } | ExecuteMsg::SetViewingKey { .. } => {
+            return Err(StdError::generic_err(
+                "This operation carries a raw secret and cannot be scheduled; call it directly instead",
+            ));
+        }
+        _ => {}
+    }
+
+    let delay = min_delay_read(deps.storage).load()?;
+    let earliest = env.block.time.plus_seconds(delay);
+    if eta < earliest {
+        return Err(StdError::generic_err(format!(
+            "eta must be at least {} seconds from now",
+            delay
+        )));
+    }
+
+    SCHEDULED_OPS
+        .insert(
+            deps.storage,
+            &id,
+            &ScheduledOperation {
+                operation: *operation,
+                eta,
+                proposer: info.sender.clone(),
+            },
+        )
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "schedule_operation")
+        .add_attribute("id", id)
+        .add_attribute("eta", eta.seconds().to_string()))
+}
+
+/// Runs the operation scheduled under `id`, as the admin that scheduled it,
+/// once its `eta` has passed. Callable by anyone: the delay itself is what
+/// authorizes the operation, not the caller of this message. Removes the
+/// entry before running it, so it can't be re-executed, and refuses to run
+/// one that's sat past `GRACE_PERIOD_SECONDS` unexecuted.
+pub fn try_execute_scheduled(mut deps: DepsMut, env: Env, id: String) -> StdResult<Response> {
+    let op = SCHEDULED_OPS
+        .get(deps.storage, &id)
+        .ok_or_else(|| StdError::generic_err("No operation scheduled under this id"))?;
+
+    if env.block.time < op.eta {
+        return Err(StdError::generic_err("Operation's eta has not yet passed"));
+    }
+    if env.block.time.seconds() > op.eta.seconds() + GRACE_PERIOD_SECONDS {
+        SCHEDULED_OPS
+            .remove(deps.storage, &id)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        return Err(StdError::generic_err(
+            "Operation has expired; cancel and reschedule it",
+        ));
+    }
+
+    SCHEDULED_OPS
+        .remove(deps.storage, &id)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let proposer_info = MessageInfo {
+        sender: op.proposer,
+        funds: vec![],
+    };
+    let response = execute(deps.branch(), env, proposer_info, op.operation)?;
+
+    Ok(response
+        .add_attribute("action", "execute_scheduled")
+        .add_attribute("id", id))
+}
+
+/// Admin-only. Drops the operation scheduled under `id` without running it.
+pub fn try_cancel_scheduled(deps: DepsMut, info: MessageInfo, id: String) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can cancel scheduled operations"));
+    }
+
+    if !SCHEDULED_OPS.contains(deps.storage, &id) {
+        return Err(StdError::generic_err("No operation scheduled under this id"));
+    }
+    SCHEDULED_OPS
+        .remove(deps.storage, &id)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_scheduled")
+        .add_attribute("id", id))
+}
+
 // Function to register a new subscriber
 pub fn try_register_subscriber(
     deps: DepsMut,
     info: MessageInfo,
     public_key: String,
+    expires: Option<Expiration>,
 ) -> StdResult<Response> {
-    // Check if the sender is the admin
-    let config = config_read(deps.storage);
-    let state = config.load()?;
-    if info.sender != state.admin {
-        return Err(StdError::generic_err("Only admin can register subscribers"));
+    // Check if the sender is an admin; if not, fall back to a granted
+    // operator permission, spending one unit of its registration budget.
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        spend_registration_budget(deps.storage, &info.sender)?;
     }
 
     // Check if the subscriber is already registered
@@ -165,7 +589,10 @@ pub fn try_register_subscriber(
     }
 
     // Create a new subscriber and insert it into the map
-    let subscriber = Subscriber { status: true };
+    let subscriber = Subscriber {
+        status: true,
+        expires: expires.unwrap_or(Expiration::Never {}),
+    };
     SB_MAP.insert(deps.storage, &public_key, &subscriber)
         .map_err(|err| StdError::generic_err(err.to_string()))?;
 
@@ -175,17 +602,177 @@ pub fn try_register_subscriber(
         .add_attribute("subscriber", public_key))
 }
 
+/// Updates an already-registered subscriber's expiration, e.g. to extend a
+/// paid subscription period. Subject to the same admin-or-operator
+/// authorization as registering, since it's the same subscriber-management
+/// right, but doesn't spend registration budget since it isn't a new
+/// registration.
+pub fn try_renew_subscriber(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_key: String,
+    expires: Expiration,
+) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        let permissions = PERMISSIONS
+            .get(deps.storage, &info.sender.to_string())
+            .ok_or_else(|| StdError::generic_err("Only admin or a registered operator can renew subscribers"))?;
+        if !permissions.can_register {
+            return Err(StdError::generic_err("Operator is not permitted to renew subscribers"));
+        }
+    }
+
+    let mut subscriber = SB_MAP
+        .get(deps.storage, &public_key)
+        .ok_or_else(|| StdError::generic_err("Subscriber not registered"))?;
+    subscriber.expires = expires;
+    SB_MAP
+        .insert(deps.storage, &public_key, &subscriber)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "renew_subscriber")
+        .add_attribute("subscriber", public_key))
+}
+
+/// Deduplicates `public_keys` (keeping first-occurrence order) and rejects
+/// the batch if it's larger than `MAX_BATCH_SIZE` once deduplicated.
+fn dedupe_and_bound_batch(public_keys: Vec<String>) -> StdResult<Vec<String>> {
+    let mut seen = HashSet::with_capacity(public_keys.len());
+    let deduped: Vec<String> = public_keys
+        .into_iter()
+        .filter(|key| seen.insert(key.clone()))
+        .collect();
+
+    if deduped.len() > MAX_BATCH_SIZE {
+        return Err(StdError::generic_err(format!(
+            "Batch size {} exceeds the maximum of {}",
+            deduped.len(),
+            MAX_BATCH_SIZE
+        )));
+    }
+
+    Ok(deduped)
+}
+
+/// Registers every key in `public_keys` with `Expiration::Never {}`, under
+/// the same authorization `try_register_subscriber` applies to a single key.
+/// All-or-nothing: the whole batch is validated (no key already registered,
+/// and an operator's remaining budget covers the whole batch) before any key
+/// is written, so a failure partway through never leaves a partial batch
+/// applied. Modeled on cw1155-base's batch transfer events: one `subscriber`
+/// attribute per affected key, so an indexer can track each one.
+pub fn try_batch_register_subscribers(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_keys: Vec<String>,
+) -> StdResult<Response> {
+    let keys = dedupe_and_bound_batch(public_keys)?;
+    let state = config_read(deps.storage).load()?;
+    let is_admin = state.is_admin(&info.sender);
+
+    // Validate phase: no storage writes yet.
+    for key in &keys {
+        if SB_MAP.contains(deps.storage, key) {
+            return Err(StdError::generic_err(format!("Subscriber already registered: {}", key)));
+        }
+    }
+    if !is_admin {
+        let permissions = PERMISSIONS
+            .get(deps.storage, &info.sender.to_string())
+            .ok_or_else(|| StdError::generic_err("Only admin or a registered operator can register subscribers"))?;
+        if !permissions.can_register {
+            return Err(StdError::generic_err("Operator is not permitted to register subscribers"));
+        }
+        if let Some(remaining) = permissions.remaining_registrations {
+            if (remaining as usize) < keys.len() {
+                return Err(StdError::generic_err(
+                    "Operator has insufficient remaining registration budget for this batch",
+                ));
+            }
+        }
+    }
+
+    // Write phase: validation above guarantees every key can be applied.
+    let mut attrs = vec![attr("action", "batch_register_subscribers")];
+    for key in &keys {
+        let subscriber = Subscriber {
+            status: true,
+            expires: Expiration::Never {},
+        };
+        SB_MAP
+            .insert(deps.storage, key, &subscriber)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        attrs.push(attr("subscriber", key.as_str()));
+    }
+
+    if !is_admin {
+        let mut permissions = PERMISSIONS.get(deps.storage, &info.sender.to_string()).unwrap();
+        if let Some(remaining) = permissions.remaining_registrations {
+            permissions.remaining_registrations = Some(remaining - keys.len() as u32);
+            PERMISSIONS
+                .insert(deps.storage, &info.sender.to_string(), &permissions)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+        }
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Removes every key in `public_keys`, under the same authorization
+/// `try_remove_subscriber` applies to a single key. All-or-nothing: every key
+/// is checked to exist before any is removed.
+pub fn try_batch_remove_subscribers(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_keys: Vec<String>,
+) -> StdResult<Response> {
+    let keys = dedupe_and_bound_batch(public_keys)?;
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        let permissions = PERMISSIONS
+            .get(deps.storage, &info.sender.to_string())
+            .ok_or_else(|| StdError::generic_err("Only admin or a registered operator can remove subscribers"))?;
+        if !permissions.can_remove {
+            return Err(StdError::generic_err("Operator is not permitted to remove subscribers"));
+        }
+    }
+
+    // Validate phase: no storage writes yet.
+    for key in &keys {
+        if !SB_MAP.contains(deps.storage, key) {
+            return Err(StdError::generic_err(format!("Subscriber not registered: {}", key)));
+        }
+    }
+
+    let mut attrs = vec![attr("action", "batch_remove_subscribers")];
+    for key in &keys {
+        SB_MAP
+            .remove(deps.storage, key)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        attrs.push(attr("subscriber", key.as_str()));
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
 // Function to remove a subscriber
 pub fn try_remove_subscriber(
     deps: DepsMut,
     info: MessageInfo,
     public_key: String,
 ) -> StdResult<Response> {
-    // Check if the sender is the admin
-    let config = config_read(deps.storage);
-    let state = config.load()?;
-    if info.sender != state.admin {
-        return Err(StdError::generic_err("Only admin can remove subscribers"));
+    // Check if the sender is an admin; if not, fall back to a granted
+    // operator permission.
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
+        let permissions = PERMISSIONS
+            .get(deps.storage, &info.sender.to_string())
+            .ok_or_else(|| StdError::generic_err("Only admin or a registered operator can remove subscribers"))?;
+        if !permissions.can_remove {
+            return Err(StdError::generic_err("Operator is not permitted to remove subscribers"));
+        }
     }
 
     // Check if the subscriber is registered
@@ -204,35 +791,123 @@ pub fn try_remove_subscriber(
         .add_attribute("subscriber", public_key))
 }
 
-// Function to set a new admin
+// Compatibility shim for AddAdmins: grants admin rights to a single address
+// without removing any existing admin.
 pub fn try_set_admin(deps: DepsMut, info: MessageInfo, public_key: String) -> StdResult<Response> {
+    try_add_admins(deps, info, vec![public_key])
+}
+
+/// Grants admin rights to one or more addresses. Only an existing admin may
+/// call this. Addresses already in the admin set are left untouched rather
+/// than erroring, so batches can be retried safely.
+pub fn try_add_admins(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_keys: Vec<String>,
+) -> StdResult<Response> {
+    let mut config = config(deps.storage);
+    let mut state = config.load()?;
+
+    // Check if the sender is an admin
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can add admins"));
+    }
+
+    let mut added = Vec::with_capacity(public_keys.len());
+    for public_key in public_keys {
+        // Validate the new admin's address
+        let addr = deps.api.addr_validate(&public_key).map_err(|err| {
+            StdError::generic_err(format!("Invalid address: {}", err))
+        })?;
+
+        if !state.admins.contains(&addr) {
+            state.admins.push(addr);
+        }
+        added.push(public_key);
+    }
+    config.save(&state)?;
+
+    // Return a response indicating successful admin update
+    Ok(Response::new()
+        .add_attribute("action", "add_admins")
+        .add_attribute("new_admins", added.join(",")))
+}
+
+/// Removes the calling admin from the admin set. Rejected if the caller is
+/// not an admin, or if it is the last remaining admin: the contract must
+/// always retain at least one admin, or it becomes permanently
+/// unadministrable.
+pub fn try_leave(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
     let mut config = config(deps.storage);
     let mut state = config.load()?;
 
-    // Check if the sender is the current admin
-    if info.sender != state.admin {
+    if !state.is_admin(&info.sender) {
+        return Err(StdError::generic_err("Only admin can leave the admin set"));
+    }
+
+    if state.admins.len() == 1 {
+        return Err(StdError::generic_err("Cannot remove the last admin"));
+    }
+
+    state.admins.retain(|addr| addr != &info.sender);
+    config.save(&state)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "leave")
+        .add_attribute("former_admin", info.sender))
+}
+
+/// Designates `public_key` as the pending admin candidate. Only an existing
+/// admin may call this; the candidate gains no rights until it calls
+/// `try_accept_admin`, so a typo in `public_key` is harmless rather than
+/// permanently bricking control of the contract.
+pub fn try_transfer_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_key: String,
+) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&info.sender) {
         return Err(StdError::generic_err("Only the current admin can set a new admin"));
     }
 
-    // Validate the new admin's public key
-    let final_address = deps.api.addr_validate(&public_key).map_err(|err| {
+    let candidate = deps.api.addr_validate(&public_key).map_err(|err| {
         StdError::generic_err(format!("Invalid address: {}", err))
     })?;
+    pending_admin(deps.storage).save(&Some(candidate))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_admin")
+        .add_attribute("pending_admin", public_key))
+}
 
-    // Update the admin in the state
-    state.admin = final_address;
+/// Promotes the caller from pending candidate to admin, and clears the
+/// pending slot. Rejected unless the caller is exactly the candidate a prior
+/// `TransferAdmin` designated.
+pub fn try_accept_admin(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+    let candidate = pending_admin(deps.storage).load()?;
+    if candidate.as_ref() != Some(&info.sender) {
+        return Err(StdError::generic_err("Only the pending admin can accept"));
+    }
+
+    let mut config = config(deps.storage);
+    let mut state = config.load()?;
+    if !state.admins.contains(&info.sender) {
+        state.admins.push(info.sender.clone());
+    }
     config.save(&state)?;
 
-    // Return a response indicating successful admin update
+    pending_admin(deps.storage).save(&None)?;
+
     Ok(Response::new()
-        .add_attribute("action", "set_admin")
-        .add_attribute("new_admin", public_key))
+        .add_attribute("action", "accept_admin")
+        .add_attribute("new_admin", info.sender))
 }
 
 // Entry point for handling queries
 #[entry_point]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
+    let response = match msg {
         QueryMsg::SubscriberStatusWithPermit { public_key, permit } => {
             to_binary(&query_subscriber_with_permit(deps, env, public_key, permit)?)
         }
@@ -240,13 +915,81 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ApiKeysWithPermit { permit } => {
             to_binary(&query_api_keys_with_permit(deps, env, permit)?)
         }
+        QueryMsg::MyApiKeysWithPermit { permit } => {
+            to_binary(&query_my_api_keys_with_permit(deps, env, permit)?)
+        }
+        QueryMsg::ApiKeys { address, key } => {
+            to_binary(&query_api_keys_with_key(deps, address, key)?)
+        }
+        QueryMsg::SubscriberStatus {
+            address,
+            key,
+            public_key,
+        } => to_binary(&query_subscriber_status_with_key(deps, env, address, key, public_key)?),
+        QueryMsg::GetPendingAdmin {} => to_binary(&get_pending_admin(deps)?),
+        QueryMsg::OperatorPermissions { public_key } => {
+            to_binary(&get_operator_permissions(deps, public_key)?)
+        }
+        QueryMsg::AllOperators {} => to_binary(&get_all_operators(deps)?),
+        QueryMsg::ListSubscribersWithPermit {
+            start_after,
+            limit,
+            permit,
+        } => to_binary(&query_list_subscribers_with_permit(deps, env, start_after, limit, permit)?),
+        QueryMsg::SubscriberCount {} => to_binary(&get_subscriber_count(deps)?),
+        QueryMsg::ScheduledOperation { id } => to_binary(&get_scheduled_operation(deps, id)?),
+        QueryMsg::ListScheduled {} => to_binary(&get_list_scheduled(deps)?),
+    };
+    pad_query_result(response, BLOCK_SIZE)
+}
+
+/// Compares two equal-length byte slices without short-circuiting on the
+/// first differing byte, so the time taken doesn't leak how many leading
+/// bytes of a guess were correct.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Checks `key` against the viewing key stored for `address`, the viewing-key
+/// equivalent of `validate`'s signature check on a permit. An address with no
+/// viewing key set is rejected with the same error as a wrong key, so callers
+/// can't distinguish "never set" from "set but wrong". The comparison itself
+/// is constant-time to avoid leaking a partial match through timing.
+fn verify_viewing_key(deps: Deps, address: &Addr, key: &str) -> StdResult<()> {
+    let key_hash: [u8; 32] = Sha256::digest(key.as_bytes()).into();
+    match VIEWING_KEY_MAP.get(deps.storage, &address.to_string()) {
+        Some(stored_hash) if constant_time_eq(&stored_hash, &key_hash) => Ok(()),
+        _ => Err(StdError::generic_err("Wrong viewing key for this address")),
     }
 }
 
-// Function to get the current admin
-fn get_admin(deps: Deps) -> StdResult<Addr> {
+// Function to get the current set of admins
+fn get_admin(deps: Deps) -> StdResult<Vec<Addr>> {
     let state = config_read(deps.storage).load()?;
-    Ok(state.admin)
+    Ok(state.admins)
+}
+
+// Function to get the pending admin candidate, if a transfer is in progress
+fn get_pending_admin(deps: Deps) -> StdResult<Option<Addr>> {
+    pending_admin_read(deps.storage).load()
+}
+
+// Function to get the permissions granted to a single operator, if any
+fn get_operator_permissions(deps: Deps, public_key: String) -> StdResult<Option<OperatorPermissions>> {
+    Ok(PERMISSIONS.get(deps.storage, &public_key))
+}
+
+// Function to list every granted operator and its permissions
+fn get_all_operators(deps: Deps) -> StdResult<AllOperatorsResponse> {
+    let operators = PERMISSIONS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(public_key, permissions)| OperatorEntry { public_key, permissions })
+        .collect();
+    Ok(AllOperatorsResponse { operators })
 }
 
 // Function to check if a subscriber is active
@@ -256,9 +999,8 @@ fn query_subscriber_with_permit(
     public_key: String,
     permit: Permit,
 ) -> StdResult<SubscriberStatusResponse> {
-    // 1. Read current admin from contract state
+    // 1. Read current admin set from contract state
     let state = config_read(deps.storage).load()?;
-    let admin_addr = state.admin;
 
     //  Validate permit name
     if permit.params.permit_name != "query_subscriber_permit" {
@@ -267,7 +1009,7 @@ fn query_subscriber_with_permit(
 
     // 2. Validate the permit
     let contract_address = env.contract.address;
-    let storage_prefix = "permits_subscriber_status";
+    let storage_prefix = PERMITS_SUBSCRIBER_STATUS_PREFIX;
     let signer_addr = validate(
         deps,
         storage_prefix,
@@ -276,14 +1018,28 @@ fn query_subscriber_with_permit(
         Some("secret"),
     )?;
 
-    // 3. Check if the signer is actually the admin
-    if signer_addr != admin_addr {
+    // 3. Reject the permit if it has been revoked by its signer
+    if RevokedPermits::is_permit_revoked(
+        deps.storage,
+        storage_prefix,
+        signer_addr.as_str(),
+        &permit.params.permit_name,
+    ) {
+        return Err(StdError::generic_err("This permit has been revoked"));
+    }
+
+    // 4. Check if the signer is an admin
+    if !state.is_admin(&signer_addr) {
         return Err(StdError::generic_err("Unauthorized: not the admin"));
     }
 
-    // 4. Check if the subscriber exists
-    let subscriber = SB_MAP.get(deps.storage, &public_key);
-    let active = subscriber.is_some();
+    // 5. A subscriber is active only if it exists, its status flag is set,
+    //    and it hasn't passed its stored expiration. An expired subscriber
+    //    still has a record in `SB_MAP`; it just reports as inactive.
+    let active = SB_MAP
+        .get(deps.storage, &public_key)
+        .map(|subscriber| subscriber.status && !subscriber.expires.is_expired(&env.block))
+        .unwrap_or(false);
 
     Ok(SubscriberStatusResponse { active })
 }
@@ -294,9 +1050,8 @@ fn query_api_keys_with_permit(
     env: Env,
     permit: Permit,
 ) -> StdResult<GetApiKeysResponse> {
-    // 1. Read current admin from contract state
+    // 1. Read current admin set from contract state
     let state = config_read(deps.storage).load()?;
-    let admin_addr = state.admin; // e.g. "secret1xyz..."
 
     //  Validate permit name
     if permit.params.permit_name != "api_keys_permit" {
@@ -312,7 +1067,7 @@ fn query_api_keys_with_permit(
 
     // 3. storage_prefix is the prefix in storage for revoked permits (if used).
     //    Typically something like "permits" or "revoke_permits".
-    let storage_prefix = "permits_api_keys";
+    let storage_prefix = PERMITS_API_KEYS_PREFIX;
 
     // 4. Validate the permit
     //    This should check:
@@ -331,8 +1086,18 @@ fn query_api_keys_with_permit(
         Some("secret"), // The HRP, e.g. "secret", "cosmos", etc.
     )?;
 
-    // 5. Check if the signer is actually the admin
-    if signer_addr != admin_addr {
+    // 5. Reject the permit if it has been revoked by its signer
+    if RevokedPermits::is_permit_revoked(
+        deps.storage,
+        storage_prefix,
+        signer_addr.as_str(),
+        &permit.params.permit_name,
+    ) {
+        return Err(StdError::generic_err("This permit has been revoked"));
+    }
+
+    // 6. Check if the signer is an admin
+    if !state.is_admin(&signer_addr) {
         return Err(StdError::generic_err("Unauthorized: not the admin"));
     }
 
@@ -353,17 +1118,313 @@ fn query_api_keys_with_permit(
 
     Ok(GetApiKeysResponse { api_keys })
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use cosmwasm_std::testing::*;
-    use cosmwasm_std::{attr, from_binary, BlockInfo, Coin, ContractInfo, Timestamp, TransactionInfo, Uint128};
 
-    fn mock_env_for_permit() -> Env {
-        let env = Env {
-            block: BlockInfo {
-                height: 12_345,
-                time: Timestamp::from_nanos(1_571_797_419_879_305_533),
+/// Admin-permit-gated, paginated enumeration of the subscriber set, walked in
+/// key order. `start_after` is an exclusive bound: the page starts with the
+/// first key strictly greater than it, following the `Bound`-style range
+/// iteration cw-storage-plus contracts use for pagination.
+fn query_list_subscribers_with_permit(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    permit: Permit,
+) -> StdResult<SubscribersResponse> {
+    let state = config_read(deps.storage).load()?;
+
+    if permit.params.permit_name != "list_subscribers_permit" {
+        return Err(StdError::generic_err("Invalid permit name"));
+    }
+
+    let contract_address = env.contract.address;
+    let storage_prefix = PERMITS_LIST_SUBSCRIBERS_PREFIX;
+    let signer_addr = validate(
+        deps,
+        storage_prefix,
+        &permit,
+        contract_address.into_string(),
+        Some("secret"),
+    )?;
+
+    if RevokedPermits::is_permit_revoked(
+        deps.storage,
+        storage_prefix,
+        signer_addr.as_str(),
+        &permit.params.permit_name,
+    ) {
+        return Err(StdError::generic_err("This permit has been revoked"));
+    }
+
+    if !state.is_admin(&signer_addr) {
+        return Err(StdError::generic_err("Unauthorized: not the admin"));
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_SUBSCRIBERS_LIMIT).min(MAX_SUBSCRIBERS_LIMIT) as usize;
+
+    let mut subscribers: Vec<(String, Subscriber)> = SB_MAP
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .filter(|(public_key, _)| {
+            start_after
+                .as_ref()
+                .map_or(true, |after| public_key.as_str() > after.as_str())
+        })
+        .collect();
+    subscribers.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let next_key = if subscribers.len() > limit {
+        subscribers.truncate(limit);
+        subscribers.last().map(|(public_key, _)| public_key.clone())
+    } else {
+        None
+    };
+
+    let subscribers = subscribers
+        .into_iter()
+        .map(|(public_key, subscriber)| SubscriberInfo {
+            public_key,
+            status: subscriber.status,
+            expires: subscriber.expires,
+        })
+        .collect();
+
+    Ok(SubscribersResponse { subscribers, next_key })
+}
+
+// Function to get the total number of registered subscribers
+fn get_subscriber_count(deps: Deps) -> StdResult<u64> {
+    Ok(SB_MAP.iter_keys(deps.storage)?.filter_map(|key| key.ok()).count() as u64)
+}
+
+/// The operation scheduled under `id`, or `None` if there isn't one.
+fn get_scheduled_operation(deps: Deps, id: String) -> StdResult<Option<ScheduledOperationResponse>> {
+    Ok(SCHEDULED_OPS
+        .get(deps.storage, &id)
+        .map(|op| ScheduledOperationResponse {
+            id,
+            operation: op.operation,
+            eta: op.eta,
+            proposer: op.proposer,
+        }))
+}
+
+/// Every currently scheduled operation.
+fn get_list_scheduled(deps: Deps) -> StdResult<ListScheduledResponse> {
+    let operations = SCHEDULED_OPS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(id, op)| ScheduledOperationResponse {
+            id,
+            operation: op.operation,
+            eta: op.eta,
+            proposer: op.proposer,
+        })
+        .collect();
+    Ok(ListScheduledResponse { operations })
+}
+
+/// Viewing-key equivalent of `query_api_keys_with_permit`: admin-only.
+fn query_api_keys_with_key(deps: Deps, address: String, key: String) -> StdResult<GetApiKeysResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    verify_viewing_key(deps, &addr, &key)?;
+
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&addr) {
+        return Err(StdError::generic_err("Unauthorized: not the admin"));
+    }
+
+    let api_keys: Vec<ApiKeyResponse> = API_KEY_MAP
+        .iter_keys(deps.storage)?
+        .filter_map(|key_result| key_result.ok().map(|hashed_key| ApiKeyResponse { hashed_key }))
+        .collect();
+
+    Ok(GetApiKeysResponse { api_keys })
+}
+
+/// Viewing-key equivalent of `query_subscriber_with_permit`, but also allows
+/// the subscriber itself (`address == public_key`) to check its own status,
+/// not just the admin.
+fn query_subscriber_status_with_key(
+    deps: Deps,
+    env: Env,
+    address: String,
+    key: String,
+    public_key: String,
+) -> StdResult<SubscriberStatusResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    verify_viewing_key(deps, &addr, &key)?;
+
+    let state = config_read(deps.storage).load()?;
+    if !state.is_admin(&addr) && address != public_key {
+        return Err(StdError::generic_err(
+            "Unauthorized: not the admin or the subscriber",
+        ));
+    }
+
+    let active = SB_MAP
+        .get(deps.storage, &public_key)
+        .map(|subscriber| subscriber.status && !subscriber.expires.is_expired(&env.block))
+        .unwrap_or(false);
+    Ok(SubscriberStatusResponse { active })
+}
+
+/// Subscriber self-service: every API key, ECIES-encrypted to the calling
+/// subscriber's x25519 public key. Unlike `query_api_keys_with_permit`, the
+/// signer must be a registered subscriber rather than an admin.
+fn query_my_api_keys_with_permit(
+    deps: Deps,
+    env: Env,
+    permit: Permit,
+) -> StdResult<MyApiKeysResponse> {
+    if permit.params.permit_name != "my_api_keys_permit" {
+        return Err(StdError::generic_err("Invalid permit name"));
+    }
+
+    let contract_address = env.contract.address.clone();
+    let storage_prefix = PERMITS_MY_API_KEYS_PREFIX;
+    let signer_addr = validate(
+        deps,
+        storage_prefix,
+        &permit,
+        contract_address.into_string(),
+        Some("secret"),
+    )?;
+
+    if RevokedPermits::is_permit_revoked(
+        deps.storage,
+        storage_prefix,
+        signer_addr.as_str(),
+        &permit.params.permit_name,
+    ) {
+        return Err(StdError::generic_err("This permit has been revoked"));
+    }
+
+    let subscriber = SB_MAP
+        .get(deps.storage, &signer_addr.to_string())
+        .ok_or_else(|| StdError::generic_err("Not a registered subscriber"))?;
+    if !subscriber.status || subscriber.expires.is_expired(&env.block) {
+        return Err(StdError::generic_err("Subscriber is not active"));
+    }
+
+    // The ECIES recipient key must be one the subscriber actually holds the
+    // private half of; it is never derived from the subscriber's (public)
+    // address, unlike e.g. a viewing key hash.
+    let pubkey_bytes = ECIES_PUBKEY_MAP
+        .get(deps.storage, &signer_addr.to_string())
+        .ok_or_else(|| {
+            StdError::generic_err("Subscriber has not set an ECIES delivery key via SetEciesPubkey")
+        })?;
+    let subscriber_pubkey = X25519PublicKey::from(pubkey_bytes);
+    let secret = api_key_secret_read(deps.storage).load()?;
+
+    let api_keys = API_KEY_MAP
+        .iter(deps.storage)?
+        .enumerate()
+        .filter_map(|(i, entry)| entry.ok().map(|(hash, api_key)| (i, hash, api_key)))
+        .map(|(i, _key_hash, api_key)| {
+            let plaintext = decrypt_at_rest(&secret, &api_key)?;
+            ecies_encrypt(&env, &subscriber_pubkey, i as u64, &plaintext)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(MyApiKeysResponse { api_keys })
+}
+
+/// Derives the ChaCha20-Poly1305 key used to encrypt API keys at rest from
+/// the per-contract secret, via HKDF-SHA256.
+fn at_rest_key(secret: &[u8]) -> [u8; 32] {
+    let hkdf = Hkdf::<Sha256>::new(None, secret);
+    let mut key = [0u8; 32];
+    hkdf.expand(AT_REST_HKDF_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// Encrypts `plaintext` for storage under `key_hash`. The nonce is derived
+/// from the secret and `key_hash` rather than randomly chosen: since every
+/// stored key has a distinct hash, this still never reuses a (key, nonce)
+/// pair, and it keeps encryption deterministic without needing an RNG inside
+/// `execute`.
+fn encrypt_at_rest(secret: &[u8], key_hash: &str, plaintext: &[u8]) -> StdResult<(Vec<u8>, Vec<u8>)> {
+    let key = at_rest_key(secret);
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let nonce_bytes: [u8; 12] = Sha256::digest([secret, key_hash.as_bytes()].concat())[..12]
+        .try_into()
+        .expect("sha256 digest is at least 12 bytes");
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| StdError::generic_err("Failed to encrypt API key"))?;
+    Ok((nonce_bytes.to_vec(), ciphertext))
+}
+
+fn decrypt_at_rest(secret: &[u8], api_key: &ApiKey) -> StdResult<Vec<u8>> {
+    let key = at_rest_key(secret);
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+    let nonce = Nonce::from_slice(&api_key.nonce);
+    cipher
+        .decrypt(nonce, api_key.ciphertext.as_ref())
+        .map_err(|_| StdError::generic_err("Failed to decrypt stored API key"))
+}
+
+/// ECIES-encrypts `plaintext` to `recipient_pubkey`: an ephemeral x25519
+/// keypair is derived from on-chain randomness (domain-separated by
+/// `delivery_index` so a response with several keys gets a distinct
+/// ephemeral key per entry), the shared secret is run through HKDF-SHA256 to
+/// get an AEAD key, and the result is ChaCha20-Poly1305-encrypted.
+fn ecies_encrypt(
+    env: &Env,
+    recipient_pubkey: &X25519PublicKey,
+    delivery_index: u64,
+    plaintext: &[u8],
+) -> StdResult<EciesApiKey> {
+    let randomness = match &env.block.random {
+        Some(random) => random.0.clone(),
+        None => Sha256::digest(env.contract.address.as_bytes()).to_vec(),
+    };
+    let mut preimage = randomness;
+    preimage.extend_from_slice(recipient_pubkey.as_bytes());
+    preimage.extend_from_slice(&delivery_index.to_be_bytes());
+    let ephemeral_seed: [u8; 32] = Sha256::digest(&preimage).into();
+    let ephemeral_secret = StaticSecret::from(ephemeral_seed);
+    let ephemeral_pubkey = X25519PublicKey::from(&ephemeral_secret);
+
+    let shared_secret = ephemeral_secret.diffie_hellman(recipient_pubkey);
+    let hkdf = Hkdf::<Sha256>::new(None, shared_secret.as_bytes());
+    let mut aead_key = [0u8; 32];
+    hkdf.expand(ECIES_HKDF_INFO, &mut aead_key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+
+    let nonce_bytes: [u8; 12] = Sha256::digest(
+        [ephemeral_pubkey.as_bytes().as_slice(), recipient_pubkey.as_bytes().as_slice()].concat(),
+    )[..12]
+        .try_into()
+        .expect("sha256 digest is at least 12 bytes");
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(&aead_key));
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|_| StdError::generic_err("Failed to encrypt API key for delivery"))?;
+
+    Ok(EciesApiKey {
+        ephemeral_pubkey: hex::encode(ephemeral_pubkey.as_bytes()),
+        nonce: hex::encode(nonce_bytes),
+        ciphertext: hex::encode(ciphertext),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{attr, from_binary, BlockInfo, Coin, ContractInfo, Timestamp, TransactionInfo, Uint128};
+
+    fn mock_env_for_permit() -> Env {
+        let env = Env {
+            block: BlockInfo {
+                height: 12_345,
+                time: Timestamp::from_nanos(1_571_797_419_879_305_533),
                 chain_id: "pulsar-3".to_string(),
                 random: Some(
                     Binary::from_base64("wLsKdf/sYqvSMI0G0aWRjob25mrIB0VQVjTjDXnDafk=").unwrap(),
@@ -383,12 +1444,12 @@ mod tests {
     }
 
     #[test]
-    fn test_migrate_clears_api_key_map() {
+    fn test_clear_api_keys_wipes_api_key_map() {
         let mut deps = mock_dependencies();
 
         // Initialize the contract with an admin address
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         // Add API keys to the `API_KEY_MAP`
@@ -420,8 +1481,8 @@ mod tests {
             .collect();
         assert_eq!(keys.len(), 2);
 
-        // Perform migration
-        migrate(deps.as_mut(), mock_env(), MigrateMsg::Migrate {}).unwrap();
+        // Perform the explicit, opt-in clearing migration
+        migrate(deps.as_mut(), mock_env(), MigrateMsg::ClearApiKeys {}).unwrap();
 
         // Ensure the keys are removed
         let keys_after_migration: Vec<String> = API_KEY_MAP
@@ -432,12 +1493,59 @@ mod tests {
         assert!(keys_after_migration.is_empty());
     }
 
+    #[test]
+    /// Test that the default `Migrate` leaves stored API keys untouched and
+    /// advances the stored version
+    fn migrate_preserves_api_key_map_and_bumps_version() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddApiKey {
+                api_key: "test_key1".to_string(),
+            },
+        )
+        .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg::Migrate {}).unwrap();
+
+        let keys: Vec<String> = API_KEY_MAP
+            .iter_keys(deps.as_ref().storage)
+            .unwrap()
+            .filter_map(|key_result| key_result.ok())
+            .collect();
+        assert_eq!(keys.len(), 1);
+
+        let version = contract_version_read(&deps.storage).load().unwrap();
+        assert_eq!(version, CONTRACT_VERSION);
+    }
+
+    #[test]
+    /// Test that migrating to an older version than what's already stored is
+    /// rejected rather than silently downgrading
+    fn migrate_rejects_downgrade() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg { min_delay: None }).unwrap();
+
+        contract_version(deps.as_mut().storage)
+            .save(&(CONTRACT_VERSION + 1))
+            .unwrap();
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg::Migrate {});
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_query_api_keys_with_real_permit() {
         // 1. Initialize the contract with admin = "secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4"
         let mut deps = mock_dependencies();
         let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Create a custom Env if you need specific block/transaction data
         let env = mock_env_for_permit();
@@ -486,7 +1594,7 @@ mod tests {
         let mut deps = mock_dependencies();
         // Suppose "admin" is just a placeholder address (like "secret1abc...")
         let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Create a custom Env if you need specific block/transaction data
         let env = mock_env_for_permit();
@@ -540,7 +1648,7 @@ mod tests {
                 amount: Uint128::new(1000),
             }],
         );
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Assert successful initialization
         let res = instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
@@ -552,11 +1660,12 @@ mod tests {
     fn register_subscriber_success() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let register_msg = ExecuteMsg::RegisterSubscriber {
             public_key: "subscriber1".to_string(),
+            expires: None,
         };
 
         // Execute the message to register the subscriber and check the response
@@ -576,12 +1685,13 @@ mod tests {
     fn register_subscriber_unauthorized() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
 
         let unauthorized_info = mock_info("not_admin", &[]);
         let register_msg = ExecuteMsg::RegisterSubscriber {
             public_key: "subscriber1".to_string(),
+            expires: None,
         };
 
         // Attempt to register with a non-admin account and expect an error
@@ -598,12 +1708,13 @@ mod tests {
     fn remove_subscriber_success() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         // Register a subscriber first
         let register_msg = ExecuteMsg::RegisterSubscriber {
             public_key: "subscriber1".to_string(),
+            expires: None,
         };
         execute(deps.as_mut(), mock_env(), info.clone(), register_msg).unwrap();
 
@@ -629,7 +1740,7 @@ mod tests {
     fn remove_subscriber_not_registered() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let remove_msg = ExecuteMsg::RemoveSubscriber {
@@ -650,7 +1761,7 @@ mod tests {
     fn set_admin_success() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let set_admin_msg = ExecuteMsg::SetAdmin {
@@ -663,14 +1774,15 @@ mod tests {
         assert_eq!(
             res.attributes,
             vec![
-                attr("action", "set_admin"),
-                attr("new_admin", "new_admin")
+                attr("action", "add_admins"),
+                attr("new_admins", "new_admin")
             ]
         );
 
-        // Check that the admin was updated successfully
+        // Check that the new admin was added without removing the old one
         let config = config_read(&deps.storage).load().unwrap();
-        assert_eq!(config.admin, Addr::unchecked("new_admin"));
+        assert!(config.is_admin(&Addr::unchecked("admin")));
+        assert!(config.is_admin(&Addr::unchecked("new_admin")));
     }
 
     #[test]
@@ -678,7 +1790,7 @@ mod tests {
     fn set_admin_unauthorized() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
 
         let unauthorized_info = mock_info("not_admin", &[]);
@@ -691,7 +1803,7 @@ mod tests {
         assert!(res.is_err());
         assert_eq!(
             res.err().unwrap(),
-            StdError::generic_err("Only the current admin can set a new admin")
+            StdError::generic_err("Only admin can add admins")
         );
     }
 
@@ -699,16 +1811,79 @@ mod tests {
     fn test_get_admin() {
         let mut deps = mock_dependencies();
         let info = mock_info("admin", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
         instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
 
         let query_msg = QueryMsg::GetAdmin {};
         let bin = query(deps.as_ref(), mock_env(), query_msg).unwrap();
-        let response: Addr = from_binary(&bin).unwrap();
+        let response: Vec<Addr> = from_binary(&bin).unwrap();
 
         println!("Response: {:#?}", response);
 
-        assert_eq!(response, Addr::unchecked("admin"));
+        assert_eq!(response, vec![Addr::unchecked("admin")]);
+    }
+
+    #[test]
+    /// Test that AddAdmins grows the admin set and the new admin gains rights
+    fn add_admins_grants_rights() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        let add_admins_msg = ExecuteMsg::AddAdmins {
+            public_keys: vec!["second_admin".to_string()],
+        };
+        execute(deps.as_mut(), mock_env(), info, add_admins_msg).unwrap();
+
+        // The newly added admin can now act as one
+        let second_info = mock_info("second_admin", &[]);
+        let register_msg = ExecuteMsg::RegisterSubscriber {
+            public_key: "subscriber1".to_string(),
+            expires: None,
+        };
+        let res = execute(deps.as_mut(), mock_env(), second_info, register_msg);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    /// Test that an admin can leave as long as another admin remains
+    fn leave_removes_caller_from_admin_set() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::AddAdmins {
+                public_keys: vec!["second_admin".to_string()],
+            },
+        )
+        .unwrap();
+
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {}).unwrap();
+
+        let config = config_read(&deps.storage).load().unwrap();
+        assert_eq!(config.admins, vec![Addr::unchecked("second_admin")]);
+    }
+
+    #[test]
+    /// Test that the last remaining admin cannot leave
+    fn leave_rejects_last_admin() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Leave {});
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap(),
+            StdError::generic_err("Cannot remove the last admin")
+        );
     }
 
     #[test]
@@ -718,7 +1893,7 @@ mod tests {
         let mut deps = mock_dependencies();
         // Suppose "admin" is just a placeholder address (like "secret1abc...")
         let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Create a custom Env if you need specific block/transaction data
         let env = mock_env_for_permit();
@@ -728,6 +1903,7 @@ mod tests {
         // Register a subscriber
         let register_msg = ExecuteMsg::RegisterSubscriber {
             public_key: "subscriber_public_key".to_string(),
+            expires: None,
         };
         execute(deps.as_mut(), env.clone(), info, register_msg).unwrap();
 
@@ -757,7 +1933,7 @@ mod tests {
         let mut deps = mock_dependencies();
         // Suppose "admin" is just a placeholder address (like "secret1abc...")
         let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Create a custom Env if you need specific block/transaction data
         let env = mock_env_for_permit();
@@ -787,7 +1963,7 @@ mod tests {
         let mut deps = mock_dependencies();
         // Suppose "admin" is just a placeholder address (like "secret1abc...")
         let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
-        let init_msg = InstantiateMsg {};
+        let init_msg = InstantiateMsg { min_delay: None };
 
         // Create a custom Env if you need specific block/transaction data
         let env = mock_env_for_permit();
@@ -797,6 +1973,7 @@ mod tests {
         // Register a subscriber
         let register_msg = ExecuteMsg::RegisterSubscriber {
             public_key: "subscriber_public_key".to_string(),
+            expires: None,
         };
         execute(deps.as_mut(), env.clone(), info.clone(), register_msg).unwrap();
 
@@ -823,4 +2000,1007 @@ mod tests {
         assert!(!response.active);
     }
 
+    #[test]
+    /// Test querying for a subscriber registered with a height-based
+    /// expiration that has already passed, expecting inactive status even
+    /// though the record still exists
+    fn query_subscriber_past_expiry_height() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+
+        // mock_env_for_permit's block height is 12_345
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
+
+        let register_msg = ExecuteMsg::RegisterSubscriber {
+            public_key: "subscriber_public_key".to_string(),
+            expires: Some(Expiration::AtHeight(100)),
+        };
+        execute(deps.as_mut(), env.clone(), info, register_msg).unwrap();
+
+        // The record still exists
+        assert!(SB_MAP.contains(&deps.storage, &"subscriber_public_key".to_string()));
+
+        let json_data = std::fs::read_to_string("./query_subscriber_permit.json")
+            .expect("Failed to read permit.json");
+        let permit: secret_toolkit::permit::Permit = serde_json::from_str(&json_data)
+            .expect("Could not parse Permit from JSON");
+
+        let query_msg = QueryMsg::SubscriberStatusWithPermit {
+            public_key: "subscriber_public_key".to_string(),
+            permit,
+        };
+        let bin = query(deps.as_ref(), env, query_msg).unwrap();
+        let response: SubscriberStatusResponse = from_binary(&bin).unwrap();
+
+        assert!(!response.active);
+    }
+
+    #[test]
+    /// Test that RenewSubscriber can extend an expired subscriber back to
+    /// active
+    fn renew_subscriber_restores_active_status() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber_public_key".to_string(),
+                expires: Some(Expiration::AtHeight(100)),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::RenewSubscriber {
+                public_key: "subscriber_public_key".to_string(),
+                expires: Expiration::Never {},
+            },
+        )
+        .unwrap();
+
+        let json_data = std::fs::read_to_string("./query_subscriber_permit.json")
+            .expect("Failed to read permit.json");
+        let permit: secret_toolkit::permit::Permit = serde_json::from_str(&json_data)
+            .expect("Could not parse Permit from JSON");
+
+        let query_msg = QueryMsg::SubscriberStatusWithPermit {
+            public_key: "subscriber_public_key".to_string(),
+            permit,
+        };
+        let bin = query(deps.as_ref(), env, query_msg).unwrap();
+        let response: SubscriberStatusResponse = from_binary(&bin).unwrap();
+
+        assert!(response.active);
+    }
+
+    #[test]
+    /// Test that a stored API key round-trips through at-rest encryption
+    fn api_key_at_rest_round_trips() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::AddApiKey {
+                api_key: "sk-live-example".to_string(),
+            },
+        )
+        .unwrap();
+
+        let secret = api_key_secret_read(&deps.storage).load().unwrap();
+        let (_, api_key) = API_KEY_MAP
+            .iter(&deps.storage)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let plaintext = decrypt_at_rest(&secret, &api_key).unwrap();
+        assert_eq!(plaintext, b"sk-live-example");
+    }
+
+    #[test]
+    /// Test that ECIES delivery is only recoverable by the intended recipient
+    fn ecies_delivery_decrypts_for_recipient_only() {
+        let env = mock_env_for_permit();
+        let recipient_secret = StaticSecret::from([7u8; 32]);
+        let recipient_pubkey = X25519PublicKey::from(&recipient_secret);
+
+        let payload = ecies_encrypt(&env, &recipient_pubkey, 0, b"sk-live-example").unwrap();
+
+        let ephemeral_pubkey_bytes: [u8; 32] =
+            hex::decode(&payload.ephemeral_pubkey).unwrap().try_into().unwrap();
+        let ephemeral_pubkey = X25519PublicKey::from(ephemeral_pubkey_bytes);
+        let shared_secret = recipient_secret.diffie_hellman(&ephemeral_pubkey);
+
+        let hkdf = Hkdf::<Sha256>::new(None, shared_secret.as_bytes());
+        let mut aead_key = [0u8; 32];
+        hkdf.expand(ECIES_HKDF_INFO, &mut aead_key).unwrap();
+
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&aead_key));
+        let nonce_bytes = hex::decode(&payload.nonce).unwrap();
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce_bytes), hex::decode(&payload.ciphertext).unwrap().as_ref())
+            .unwrap();
+
+        assert_eq!(plaintext, b"sk-live-example");
+    }
+
+    #[test]
+    /// Test that RevokePermit revokes `permit_name` under every prefix a
+    /// query's `validate` call might check it against, not just the API keys
+    /// and subscriber status prefixes
+    fn revoke_permit_covers_every_prefix() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::RevokePermit {
+                permit_name: "shared_permit".to_string(),
+            },
+        )
+        .unwrap();
+
+        for prefix in [
+            PERMITS_API_KEYS_PREFIX,
+            PERMITS_SUBSCRIBER_STATUS_PREFIX,
+            PERMITS_MY_API_KEYS_PREFIX,
+            PERMITS_LIST_SUBSCRIBERS_PREFIX,
+        ] {
+            assert!(RevokedPermits::is_permit_revoked(
+                &deps.storage,
+                prefix,
+                info.sender.as_str(),
+                "shared_permit",
+            ));
+        }
+    }
+
+    #[test]
+    /// Test that constant_time_eq agrees with == on equal, differing, and
+    /// differing-length inputs
+    fn constant_time_eq_matches_equality() {
+        assert!(constant_time_eq(b"same bytes", b"same bytes"));
+        assert!(!constant_time_eq(b"same bytes", b"diff bytes"));
+        assert!(!constant_time_eq(b"short", b"longer input"));
+    }
+
+    #[test]
+    /// Test that a viewing key created via CreateViewingKey authenticates the
+    /// admin-only ApiKeys query, and a wrong key is rejected
+    fn create_viewing_key_authenticates_api_keys_query() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::AddApiKey {
+                api_key: "test_key1".to_string(),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::CreateViewingKey {
+                entropy: "some entropy".to_string(),
+            },
+        )
+        .unwrap();
+        let created: CreateViewingKeyResponse = from_binary(&res.data.unwrap()).unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ApiKeys {
+                address: "admin".to_string(),
+                key: created.key,
+            },
+        )
+        .unwrap();
+        let response: GetApiKeysResponse = from_binary(&bin).unwrap();
+        assert_eq!(response.api_keys.len(), 1);
+
+        let err = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ApiKeys {
+                address: "admin".to_string(),
+                key: "wrong key".to_string(),
+            },
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    /// Test that SetViewingKey lets a subscriber check its own status via
+    /// SubscriberStatus, without being an admin
+    fn set_viewing_key_lets_subscriber_check_own_status() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber1".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+        let subscriber_info = mock_info("subscriber1", &[]);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            subscriber_info,
+            ExecuteMsg::SetViewingKey {
+                key: "my_key".to_string(),
+            },
+        )
+        .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::SubscriberStatus {
+                address: "subscriber1".to_string(),
+                key: "my_key".to_string(),
+                public_key: "subscriber1".to_string(),
+            },
+        )
+        .unwrap();
+        let response: SubscriberStatusResponse = from_binary(&bin).unwrap();
+        assert!(response.active);
+    }
+
+    #[test]
+    /// Test that SetEciesPubkey rejects a value that isn't 32 bytes of hex
+    fn set_ecies_pubkey_rejects_wrong_length() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::SetEciesPubkey {
+                pubkey: hex::encode([1u8; 16]),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// Test that no ECIES recipient key is on file for a subscriber until it
+    /// calls SetEciesPubkey, and that the key stored afterwards is exactly
+    /// the one the subscriber supplied
+    fn set_ecies_pubkey_stores_subscriber_supplied_key() {
+        let mut deps = mock_dependencies();
+        let admin_info = mock_info("admin", &[]);
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), admin_info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            admin_info,
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber1".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+        // Before SetEciesPubkey, the subscriber has no delivery key on file.
+        assert!(!ECIES_PUBKEY_MAP.contains(&deps.storage, &"subscriber1".to_string()));
+
+        let subscriber_info = mock_info("subscriber1", &[]);
+        let recipient_secret = StaticSecret::from([9u8; 32]);
+        let recipient_pubkey = X25519PublicKey::from(&recipient_secret);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            subscriber_info,
+            ExecuteMsg::SetEciesPubkey {
+                pubkey: hex::encode(recipient_pubkey.as_bytes()),
+            },
+        )
+        .unwrap();
+
+        let stored = ECIES_PUBKEY_MAP
+            .get(&deps.storage, &"subscriber1".to_string())
+            .unwrap();
+        assert_eq!(stored, *recipient_pubkey.as_bytes());
+    }
+
+    #[test]
+    /// Test the full transfer_admin -> accept_admin handover, and that
+    /// GetPendingAdmin reflects each stage
+    fn transfer_admin_then_accept_completes_handover() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::GetPendingAdmin {}).unwrap();
+        let pending: Option<Addr> = from_binary(&bin).unwrap();
+        assert_eq!(pending, None);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::TransferAdmin {
+                public_key: "new_admin".to_string(),
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::GetPendingAdmin {}).unwrap();
+        let pending: Option<Addr> = from_binary(&bin).unwrap();
+        assert_eq!(pending, Some(Addr::unchecked("new_admin")));
+
+        let candidate_info = mock_info("new_admin", &[]);
+        execute(deps.as_mut(), mock_env(), candidate_info, ExecuteMsg::AcceptAdmin {}).unwrap();
+
+        let config = config_read(&deps.storage).load().unwrap();
+        assert!(config.is_admin(&Addr::unchecked("admin")));
+        assert!(config.is_admin(&Addr::unchecked("new_admin")));
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::GetPendingAdmin {}).unwrap();
+        let pending: Option<Addr> = from_binary(&bin).unwrap();
+        assert_eq!(pending, None);
+    }
+
+    #[test]
+    /// Test that a non-admin cannot start an admin transfer
+    fn transfer_admin_unauthorized() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+        let unauthorized_info = mock_info("not_admin", &[]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            unauthorized_info,
+            ExecuteMsg::TransferAdmin {
+                public_key: "new_admin".to_string(),
+            },
+        );
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap(),
+            StdError::generic_err("Only the current admin can set a new admin")
+        );
+    }
+
+    #[test]
+    /// Test that only the designated pending candidate can accept, not the
+    /// current admin or an unrelated address
+    fn accept_admin_rejects_non_candidate() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::TransferAdmin {
+                public_key: "new_admin".to_string(),
+            },
+        )
+        .unwrap();
+
+        let res = execute(deps.as_mut(), mock_env(), info, ExecuteMsg::AcceptAdmin {});
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap(),
+            StdError::generic_err("Only the pending admin can accept")
+        );
+    }
+
+    #[test]
+    /// Test that a granted operator can register subscribers up to its
+    /// budget and is rejected once it's exhausted
+    fn granted_operator_can_register_within_budget() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::GrantOperator {
+                public_key: "operator1".to_string(),
+                permissions: OperatorPermissions {
+                    can_register: true,
+                    can_remove: false,
+                    remaining_registrations: Some(1),
+                },
+            },
+        )
+        .unwrap();
+
+        let operator_info = mock_info("operator1", &[]);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            operator_info.clone(),
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber1".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+        // The budget is now exhausted
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            operator_info.clone(),
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber2".to_string(),
+                expires: None,
+            },
+        );
+        assert!(res.is_err());
+
+        // Nor can it remove, since `can_remove` wasn't granted
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            operator_info,
+            ExecuteMsg::RemoveSubscriber {
+                public_key: "subscriber1".to_string(),
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    /// Test that an address with no granted permissions cannot register or
+    /// remove subscribers
+    fn unrelated_address_cannot_register_subscribers() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+
+        let unauthorized_info = mock_info("nobody", &[]);
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            unauthorized_info,
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber1".to_string(),
+                expires: None,
+            },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    /// Test that RevokeOperator removes granted permissions, and AllOperators
+    /// reflects the grant/revoke lifecycle
+    fn revoke_operator_removes_permissions() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::GrantOperator {
+                public_key: "operator1".to_string(),
+                permissions: OperatorPermissions {
+                    can_register: true,
+                    can_remove: true,
+                    remaining_registrations: None,
+                },
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::AllOperators {}).unwrap();
+        let response: AllOperatorsResponse = from_binary(&bin).unwrap();
+        assert_eq!(response.operators.len(), 1);
+        assert_eq!(response.operators[0].public_key, "operator1");
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::RevokeOperator {
+                public_key: "operator1".to_string(),
+            },
+        )
+        .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::OperatorPermissions {
+                public_key: "operator1".to_string(),
+            },
+        )
+        .unwrap();
+        let permissions: Option<OperatorPermissions> = from_binary(&bin).unwrap();
+        assert_eq!(permissions, None);
+    }
+
+    #[test]
+    /// Test that BatchRegisterSubscribers registers every deduplicated key
+    fn batch_register_subscribers_deduplicates_and_registers_all() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::BatchRegisterSubscribers {
+                public_keys: vec![
+                    "subscriber1".to_string(),
+                    "subscriber2".to_string(),
+                    "subscriber1".to_string(),
+                ],
+            },
+        )
+        .unwrap();
+
+        // Only 2 `subscriber` attributes: the duplicate wasn't registered twice
+        let subscriber_attrs: Vec<_> = res
+            .attributes
+            .iter()
+            .filter(|a| a.key == "subscriber")
+            .collect();
+        assert_eq!(subscriber_attrs.len(), 2);
+
+        assert!(SB_MAP.contains(&deps.storage, &"subscriber1".to_string()));
+        assert!(SB_MAP.contains(&deps.storage, &"subscriber2".to_string()));
+    }
+
+    #[test]
+    /// Test that a BatchRegisterSubscribers batch containing an
+    /// already-registered key rolls back the whole batch
+    fn batch_register_subscribers_is_all_or_nothing() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::RegisterSubscriber {
+                public_key: "subscriber2".to_string(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::BatchRegisterSubscribers {
+                public_keys: vec!["subscriber1".to_string(), "subscriber2".to_string()],
+            },
+        );
+        assert!(res.is_err());
+
+        // subscriber1 comes before the already-registered key in the same
+        // batch, but the batch as a whole must be treated as if it never ran
+        assert!(!SB_MAP.contains(&deps.storage, &"subscriber1".to_string()));
+    }
+
+    #[test]
+    /// Test that a batch larger than MAX_BATCH_SIZE is rejected
+    fn batch_register_subscribers_rejects_oversized_batch() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        let public_keys = (0..=MAX_BATCH_SIZE).map(|i| format!("subscriber{}", i)).collect();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::BatchRegisterSubscribers { public_keys },
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    /// Test that BatchRemoveSubscribers removes every key and is
+    /// all-or-nothing if one isn't registered
+    fn batch_remove_subscribers_removes_all_or_nothing() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::BatchRegisterSubscribers {
+                public_keys: vec!["subscriber1".to_string(), "subscriber2".to_string()],
+            },
+        )
+        .unwrap();
+
+        // One key doesn't exist, so nothing should be removed
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::BatchRemoveSubscribers {
+                public_keys: vec!["subscriber1".to_string(), "nonexistent".to_string()],
+            },
+        );
+        assert!(res.is_err());
+        assert!(SB_MAP.contains(&deps.storage, &"subscriber1".to_string()));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::BatchRemoveSubscribers {
+                public_keys: vec!["subscriber1".to_string(), "subscriber2".to_string()],
+            },
+        )
+        .unwrap();
+        assert!(!SB_MAP.contains(&deps.storage, &"subscriber1".to_string()));
+        assert!(!SB_MAP.contains(&deps.storage, &"subscriber2".to_string()));
+    }
+
+    #[test]
+    fn test_subscriber_count() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg { min_delay: None }).unwrap();
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::SubscriberCount {}).unwrap();
+        let count: u64 = from_binary(&bin).unwrap();
+        assert_eq!(count, 0);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::BatchRegisterSubscribers {
+                public_keys: vec!["subscriber1".to_string(), "subscriber2".to_string()],
+            },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::SubscriberCount {}).unwrap();
+        let count: u64 = from_binary(&bin).unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    /// Test that ListSubscribersWithPermit pages through the subscriber set
+    /// in key order, using `next_key` as the following page's `start_after`
+    fn list_subscribers_with_permit_pages_in_key_order() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("secret1p55wr2n6f63wyap8g9dckkxmf4wvq73ensxrw4", &[]);
+        let init_msg = InstantiateMsg { min_delay: None };
+        let env = mock_env_for_permit();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), init_msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::BatchRegisterSubscribers {
+                public_keys: vec![
+                    "subscriber_a".to_string(),
+                    "subscriber_b".to_string(),
+                    "subscriber_c".to_string(),
+                ],
+            },
+        )
+        .unwrap();
+
+        let json_data = std::fs::read_to_string("./list_subscribers_permit.json")
+            .expect("Failed to read permit.json");
+        let permit: Permit = serde_json::from_str(&json_data)
+            .expect("Could not parse Permit from JSON");
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ListSubscribersWithPermit {
+                start_after: None,
+                limit: Some(2),
+                permit: permit.clone(),
+            },
+        )
+        .unwrap();
+        let page1: SubscribersResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            page1.subscribers.iter().map(|s| s.public_key.clone()).collect::<Vec<_>>(),
+            vec!["subscriber_a".to_string(), "subscriber_b".to_string()]
+        );
+        assert_eq!(page1.next_key, Some("subscriber_b".to_string()));
+
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ListSubscribersWithPermit {
+                start_after: page1.next_key,
+                limit: Some(2),
+                permit,
+            },
+        )
+        .unwrap();
+        let page2: SubscribersResponse = from_binary(&bin).unwrap();
+        assert_eq!(
+            page2.subscribers.iter().map(|s| s.public_key.clone()).collect::<Vec<_>>(),
+            vec!["subscriber_c".to_string()]
+        );
+        assert_eq!(page2.next_key, None);
+    }
+
+    #[test]
+    /// Only an admin can schedule a timelocked operation.
+    fn non_admin_cannot_schedule_operation() {
+        let mut deps = mock_dependencies();
+        let admin_info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(deps.as_mut(), env.clone(), admin_info, InstantiateMsg { min_delay: None }).unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("attacker", &[]),
+            ExecuteMsg::ScheduleOperation {
+                id: "op1".to_string(),
+                operation: Box::new(ExecuteMsg::Leave {}),
+                eta: env.block.time.plus_seconds(100),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `ScheduleOperation` rejects an `eta` that doesn't clear the
+    /// instantiate-configured `min_delay`.
+    fn schedule_operation_rejects_eta_below_min_delay() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            InstantiateMsg { min_delay: Some(3600) },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::ScheduleOperation {
+                id: "op1".to_string(),
+                operation: Box::new(ExecuteMsg::Leave {}),
+                eta: env.block.time.plus_seconds(10),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `ScheduleOperation` refuses to queue an operation carrying a raw
+    /// secret, since `ListScheduled`/`ScheduledOperation` would otherwise
+    /// leak it in plaintext for the whole delay window.
+    fn schedule_operation_rejects_secret_carrying_variants() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ScheduleOperation {
+                id: "op1".to_string(),
+                operation: Box::new(ExecuteMsg::AddApiKey {
+                    api_key: "plaintext_key".to_string(),
+                }),
+                eta: env.block.time.plus_seconds(100),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::ScheduleOperation {
+                id: "op2".to_string(),
+                operation: Box::new(ExecuteMsg::SetViewingKey {
+                    key: "plaintext_viewing_key".to_string(),
+                }),
+                eta: env.block.time.plus_seconds(100),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `ExecuteScheduled` refuses to run an operation before its `eta` has
+    /// passed.
+    fn execute_scheduled_rejects_before_eta() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::ScheduleOperation {
+                id: "op1".to_string(),
+                operation: Box::new(ExecuteMsg::Leave {}),
+                eta: env.block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteScheduled { id: "op1".to_string() },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// Once `eta` passes, `ExecuteScheduled` runs the stored operation as the
+    /// admin that scheduled it, regardless of who calls it, and the entry
+    /// can't be re-executed afterwards.
+    fn execute_scheduled_runs_operation_after_eta_and_cannot_rerun() {
+        let mut deps = mock_dependencies();
+        let admin_info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            admin_info.clone(),
+            InstantiateMsg { min_delay: None },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            admin_info,
+            ExecuteMsg::ScheduleOperation {
+                id: "add_admin".to_string(),
+                operation: Box::new(ExecuteMsg::AddAdmins {
+                    public_keys: vec!["new_admin".to_string()],
+                }),
+                eta: env.block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap();
+
+        let mut later_env = env.clone();
+        later_env.block.time = env.block.time.plus_seconds(1000);
+
+        execute(
+            deps.as_mut(),
+            later_env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteScheduled { id: "add_admin".to_string() },
+        )
+        .unwrap();
+
+        let bin = query(deps.as_ref(), later_env.clone(), QueryMsg::GetAdmin {}).unwrap();
+        let admins: Vec<Addr> = from_binary(&bin).unwrap();
+        assert!(admins.contains(&Addr::unchecked("new_admin")));
+
+        let err = execute(
+            deps.as_mut(),
+            later_env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteScheduled { id: "add_admin".to_string() },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `CancelScheduled` drops a pending operation so it can no longer be run.
+    fn cancel_scheduled_removes_pending_operation() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("admin", &[]);
+        let env = mock_env();
+        instantiate(deps.as_mut(), env.clone(), info.clone(), InstantiateMsg { min_delay: None })
+            .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::ScheduleOperation {
+                id: "op1".to_string(),
+                operation: Box::new(ExecuteMsg::Leave {}),
+                eta: env.block.time.plus_seconds(1000),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::CancelScheduled { id: "op1".to_string() },
+        )
+        .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ScheduledOperation { id: "op1".to_string() },
+        )
+        .unwrap();
+        let scheduled: Option<ScheduledOperationResponse> = from_binary(&bin).unwrap();
+        assert!(scheduled.is_none());
+    }
 }
\ No newline at end of file
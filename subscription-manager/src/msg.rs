@@ -0,0 +1,249 @@
+use cosmwasm_std::{Addr, Timestamp};
+use schemars::JsonSchema;
+use secret_toolkit::permit::Permit;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Expiration, OperatorPermissions};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    /// Minimum delay, in seconds, `ScheduleOperation` must enforce between
+    /// being called and the `eta` it requests. Defaults to 0 (no minimum)
+    /// if omitted.
+    pub min_delay: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// `expires` defaults to `Expiration::Never {}` if omitted.
+    RegisterSubscriber {
+        public_key: String,
+        expires: Option<Expiration>,
+    },
+    RemoveSubscriber { public_key: String },
+    /// Updates an already-registered subscriber's expiration, e.g. to extend
+    /// a paid subscription period. Errors if the subscriber isn't registered.
+    RenewSubscriber {
+        public_key: String,
+        expires: Expiration,
+    },
+    /// Registers every key in `public_keys` (deduplicated, bounded by
+    /// `MAX_BATCH_SIZE`) with `Expiration::Never {}`, under the same
+    /// authorization as `RegisterSubscriber`. All-or-nothing: if any key is
+    /// already registered or the batch exceeds the size limit, none of them
+    /// are registered.
+    BatchRegisterSubscribers { public_keys: Vec<String> },
+    /// Removes every key in `public_keys` (deduplicated, bounded by
+    /// `MAX_BATCH_SIZE`), under the same authorization as `RemoveSubscriber`.
+    /// All-or-nothing: if any key isn't registered, none of them are removed.
+    BatchRemoveSubscribers { public_keys: Vec<String> },
+    /// Compatibility shim: adds `public_key` to the admin set. Does not
+    /// remove any existing admin; prefer `AddAdmins` for new integrations.
+    SetAdmin { public_key: String },
+    AddAdmins { public_keys: Vec<String> },
+    /// The calling admin removes itself from the admin set. Rejected if it
+    /// would leave the contract with no admins.
+    Leave {},
+    AddApiKey { api_key: String },
+    RevokeApiKey { api_key: String },
+    RevokePermit { permit_name: String },
+    /// Sets the caller's viewing key to `key` directly, overwriting any
+    /// existing key. Prefer `CreateViewingKey` unless integrating with a
+    /// fixed, pre-agreed key.
+    SetViewingKey { key: String },
+    /// Derives a fresh viewing key for the caller from `entropy` and
+    /// on-chain randomness, stores its hash, and returns the key in the
+    /// response data.
+    CreateViewingKey { entropy: String },
+    /// Subscriber self-service: sets the caller's x25519 public key (hex
+    /// encoded) as the ECIES recipient key for `MyApiKeysWithPermit`
+    /// deliveries, overwriting any previously set key. Must be called with a
+    /// key whose private half the caller actually holds; unlike a viewing
+    /// key there is no safe derivation from the caller's address alone.
+    SetEciesPubkey { pubkey: String },
+    /// Callable only by a current admin. Designates `public_key` as the
+    /// pending admin candidate; it gains no rights until it calls
+    /// `AcceptAdmin`, so a typo here can't brick the contract the way
+    /// overwriting the admin in one shot would.
+    TransferAdmin { public_key: String },
+    /// Callable only by the pending admin candidate set via `TransferAdmin`.
+    /// Adds the caller to the admin set and clears the pending slot.
+    AcceptAdmin {},
+    /// Admin-only. Grants `public_key` the given scoped permissions,
+    /// overwriting any permissions previously granted to it.
+    GrantOperator {
+        public_key: String,
+        permissions: OperatorPermissions,
+    },
+    /// Admin-only. Revokes all permissions previously granted to `public_key`.
+    RevokeOperator { public_key: String },
+    /// Admin-only. Queues `operation` to run no earlier than `eta`, which
+    /// must be at least the instantiate-configured `min_delay` seconds from
+    /// now. `id` must not already have a scheduled operation pending.
+    /// Rejects `AddApiKey`/`SetViewingKey`, since `ScheduledOperation`/
+    /// `ListScheduled` return the pending operation verbatim and would leak
+    /// their raw secret for the whole delay window; call those directly
+    /// instead.
+    ScheduleOperation {
+        id: String,
+        operation: Box<ExecuteMsg>,
+        eta: Timestamp,
+    },
+    /// Runs the operation scheduled under `id` once `eta` has passed, as the
+    /// admin that scheduled it. Callable by anyone: the timelock, not the
+    /// caller, is what authorizes the operation. Rejected if `id` has no
+    /// scheduled operation, `eta` hasn't passed yet, or the operation has sat
+    /// unexecuted past its grace period.
+    ExecuteScheduled { id: String },
+    /// Admin-only. Drops the operation scheduled under `id` without running
+    /// it.
+    CancelScheduled { id: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    SubscriberStatusWithPermit {
+        public_key: String,
+        permit: Permit,
+    },
+    GetAdmin {},
+    ApiKeysWithPermit {
+        permit: Permit,
+    },
+    /// Subscriber self-service: returns every API key ECIES-encrypted to the
+    /// calling subscriber's x25519 public key, rather than the admin-only
+    /// hashes returned by `ApiKeysWithPermit`.
+    MyApiKeysWithPermit {
+        permit: Permit,
+    },
+    /// Viewing-key equivalent of `ApiKeysWithPermit`: admin-only.
+    ApiKeys {
+        address: String,
+        key: String,
+    },
+    /// Viewing-key equivalent of `SubscriberStatusWithPermit`, but also
+    /// allows the subscriber itself (`address == public_key`) to check its
+    /// own status, not just the admin.
+    SubscriberStatus {
+        address: String,
+        key: String,
+        public_key: String,
+    },
+    /// The address awaiting `AcceptAdmin`, if a `TransferAdmin` is pending.
+    GetPendingAdmin {},
+    /// The permissions granted to `public_key`, or `None` if it isn't a
+    /// registered operator.
+    OperatorPermissions { public_key: String },
+    /// Every granted operator and its permissions.
+    AllOperators {},
+    /// Admin-only, paginated enumeration of the subscriber set, walked in key
+    /// order. `start_after` is an exclusive bound: the page starts with the
+    /// first key strictly greater than it. `limit` defaults to 30 and is
+    /// capped at 100.
+    ListSubscribersWithPermit {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        permit: Permit,
+    },
+    /// The total number of registered subscribers.
+    SubscriberCount {},
+    /// The operation scheduled under `id`, or `None` if there isn't one
+    /// (including if it already ran or was cancelled).
+    ScheduledOperation { id: String },
+    /// Every currently scheduled operation.
+    ListScheduled {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriberStatusResponse {
+    pub active: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApiKeyResponse {
+    pub hashed_key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetApiKeysResponse {
+    pub api_keys: Vec<ApiKeyResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatorEntry {
+    pub public_key: String,
+    pub permissions: OperatorPermissions,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AllOperatorsResponse {
+    pub operators: Vec<OperatorEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriberInfo {
+    pub public_key: String,
+    pub status: bool,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscribersResponse {
+    pub subscribers: Vec<SubscriberInfo>,
+    pub next_key: Option<String>,
+}
+
+/// An API key ECIES-encrypted to a subscriber's x25519 public key: the
+/// sender's ephemeral public key, the AEAD nonce, and the ciphertext, all
+/// hex-encoded. Only the holder of the matching x25519 private key can
+/// recover the plaintext.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EciesApiKey {
+    pub ephemeral_pubkey: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MyApiKeysResponse {
+    pub api_keys: Vec<EciesApiKey>,
+}
+
+/// Returned as the `data` field of a `CreateViewingKey` execute response,
+/// since the key is generated on-chain rather than supplied by the caller.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CreateViewingKeyResponse {
+    pub key: String,
+}
+
+/// A scheduled operation as returned by `ScheduledOperation`/`ListScheduled`,
+/// i.e. a `ScheduledOperation` (the stored type) together with the `id` it
+/// was scheduled under.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduledOperationResponse {
+    pub id: String,
+    pub operation: ExecuteMsg,
+    pub eta: Timestamp,
+    pub proposer: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListScheduledResponse {
+    pub operations: Vec<ScheduledOperationResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrateMsg {
+    /// Brings the contract's stored schema version up to date. Refuses to run
+    /// against a version newer than the code being deployed, and otherwise
+    /// leaves `API_KEY_MAP`/`SB_MAP` untouched: there are no data
+    /// transformations defined yet.
+    Migrate {},
+    /// Explicit opt-in to wipe `API_KEY_MAP`, for the rare case where that is
+    /// actually desired. Never run implicitly by `Migrate {}`.
+    ClearApiKeys {},
+    StdError {},
+}
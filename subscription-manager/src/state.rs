@@ -0,0 +1,157 @@
+use cosmwasm_std::{Addr, BlockInfo, Timestamp};
+use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::ExecuteMsg;
+
+pub static CONFIG_KEY: &[u8] = b"config";
+pub static API_KEY_SECRET_KEY: &[u8] = b"api_key_secret";
+pub static CONTRACT_VERSION_KEY: &[u8] = b"contract_version";
+pub static PENDING_ADMIN_KEY: &[u8] = b"pending_admin";
+pub static MIN_DELAY_KEY: &[u8] = b"min_delay";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct State {
+    pub admins: Vec<Addr>,
+}
+
+impl State {
+    pub fn is_admin(&self, addr: &Addr) -> bool {
+        self.admins.contains(addr)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Subscriber {
+    pub status: bool,
+    pub expires: Expiration,
+}
+
+/// When a subscriber's registration lapses, like cw721/cw-utils'
+/// `Expiration`. Stored per subscriber so a registration can be time-boxed
+/// (e.g. a paid subscription period) without the record itself needing to be
+/// removed when it lapses.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    AtHeight(u64),
+    AtTime(Timestamp),
+    Never {},
+}
+
+impl Expiration {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            Expiration::AtHeight(height) => block.height >= *height,
+            Expiration::AtTime(time) => block.time >= *time,
+            Expiration::Never {} => false,
+        }
+    }
+}
+
+/// An API key stored encrypted at rest under the contract's secret (see
+/// `api_key_secret`), so it can later be decrypted and re-encrypted (via
+/// ECIES) for delivery to active subscribers, instead of only ever being
+/// retrievable as a hash.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApiKey {
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+pub static API_KEY_MAP: Keymap<String, ApiKey> = Keymap::new(b"api_keys");
+pub static SB_MAP: Keymap<String, Subscriber> = Keymap::new(b"subscribers");
+
+/// A scoped permission set an admin can grant to a non-admin operator
+/// address, following the cw1-subkeys model: the operator may call
+/// `RegisterSubscriber`/`RemoveSubscriber` within these bounds instead of
+/// needing to share the admin key. `remaining_registrations` starts at the
+/// cap the admin granted and is decremented on each successful registration;
+/// `None` means unlimited.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatorPermissions {
+    pub can_register: bool,
+    pub can_remove: bool,
+    pub remaining_registrations: Option<u32>,
+}
+
+pub static PERMISSIONS: Keymap<String, OperatorPermissions> = Keymap::new(b"permissions");
+
+/// SNIP-style viewing keys: `Sha256(key)` keyed by the owning address, so a
+/// leaked hash can't be used to forge the key itself.
+pub static VIEWING_KEY_MAP: Keymap<String, [u8; 32]> = Keymap::new(b"viewing_keys");
+
+/// A subscriber-supplied x25519 public key, keyed by the owning address, used
+/// as the ECIES recipient key for API key delivery. Set via `SetEciesPubkey`
+/// and never derived on-chain: unlike a viewing key or a chain address, this
+/// key's private half must never be recoverable from public information, or
+/// anyone who knows a subscriber's address could decrypt its delivered keys.
+pub static ECIES_PUBKEY_MAP: Keymap<String, [u8; 32]> = Keymap::new(b"ecies_pubkeys");
+
+/// A sensitive `ExecuteMsg` queued by `ScheduleOperation`, CW3-Timelock-style:
+/// it can only run once `eta` has passed, giving observers a guaranteed
+/// window to react (e.g. to an admin change) before it takes effect.
+/// `proposer` is the admin that scheduled it, and is who the operation
+/// executes as once `ExecuteScheduled` is called, regardless of which address
+/// actually calls `ExecuteScheduled`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduledOperation {
+    pub operation: ExecuteMsg,
+    pub eta: Timestamp,
+    pub proposer: Addr,
+}
+
+pub static SCHEDULED_OPS: Keymap<String, ScheduledOperation> = Keymap::new(b"scheduled_ops");
+
+pub fn config(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<State> {
+    singleton(storage, CONFIG_KEY)
+}
+
+pub fn config_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<State> {
+    singleton_read(storage, CONFIG_KEY)
+}
+
+/// Per-contract secret used to derive the key that encrypts API keys at
+/// rest. Seeded once at `instantiate`.
+pub fn api_key_secret(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<Vec<u8>> {
+    singleton(storage, API_KEY_SECRET_KEY)
+}
+
+pub fn api_key_secret_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<Vec<u8>> {
+    singleton_read(storage, API_KEY_SECRET_KEY)
+}
+
+/// Schema version of the data stored by this contract, so `migrate` can tell
+/// which data transformations (if any) are needed to bring an older deploy up
+/// to date, instead of blindly re-running every transformation on every
+/// upgrade.
+pub fn contract_version(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<u64> {
+    singleton(storage, CONTRACT_VERSION_KEY)
+}
+
+pub fn contract_version_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<u64> {
+    singleton_read(storage, CONTRACT_VERSION_KEY)
+}
+
+/// Candidate address from a `TransferAdmin` that hasn't yet called
+/// `AcceptAdmin`. Holds `None` once there is no pending handover, rather than
+/// the key being absent, so `GetPendingAdmin` always has a value to return.
+pub fn pending_admin(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<Option<Addr>> {
+    singleton(storage, PENDING_ADMIN_KEY)
+}
+
+pub fn pending_admin_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<Option<Addr>> {
+    singleton_read(storage, PENDING_ADMIN_KEY)
+}
+
+/// Minimum number of seconds that must elapse between `ScheduleOperation` and
+/// the `eta` it requests, set once at `instantiate`.
+pub fn min_delay(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<u64> {
+    singleton(storage, MIN_DELAY_KEY)
+}
+
+pub fn min_delay_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<u64> {
+    singleton_read(storage, MIN_DELAY_KEY)
+}
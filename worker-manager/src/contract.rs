@@ -0,0 +1,1565 @@
+use cosmwasm_std::{
+    entry_point, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, SubMsg, WasmMsg,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::encoding::EncodedBytes;
+use crate::msg::{
+    ExecuteMsg, GetLivelinessChallengeResponse, GetWorkerLivelinessResponse, GetWorkersResponse,
+    InstantiateMsg, MigrateMsg, QueryMsg, VerifyAttestationResponse,
+};
+use crate::state::{
+    authority_set, authority_set_read, config, config_read, liveliness_secret,
+    liveliness_secret_read, next_notification_id, AttestationAuthoritySet, Notification,
+    NotificationEvent, NotificationStatus, State, SubscriberCallback, Worker, WorkerType,
+    ALLOWED_MEASUREMENTS, ALLOWED_SUBSCRIBERS, CONSUMED_VAA_SEQUENCES, HIGHEST_VAA_SEQUENCE,
+    LIVELINESS_EPOCH_SECONDS, LIVELINESS_STALENESS_SECONDS, NOTIFICATIONS, SUBSCRIBER_CALLBACKS,
+    WORKERS,
+};
+
+/// The canonical shape an `attestation_report` string decodes to: the
+/// enclave measurement, the public key the worker is attesting to, and the
+/// guardian-set signatures over the length-prefixed concatenation of
+/// `measurement`, `reported_pubkey`, and `ip_address` (see
+/// `length_prefixed_message`).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttestationReport {
+    pub measurement: Vec<u8>,
+    pub reported_pubkey: Vec<u8>,
+    pub signatures: Vec<GuardianSignature>,
+}
+
+/// A single recoverable secp256k1 signature from a guardian in the current
+/// authority set, keyed by its index into `AttestationAuthoritySet::signers`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GuardianSignature {
+    pub signer_index: u32,
+    /// 64-byte compact (r || s) signature.
+    pub signature: Vec<u8>,
+    pub recovery_id: u8,
+}
+
+/// Concatenates `fields`, each preceded by its length as a big-endian `u32`,
+/// so a guardian-signed message over variable-length fields can't be
+/// reinterpreted by shifting bytes across a field boundary while hashing
+/// identically (a bare concatenation can: the signature binds the bytes, not
+/// where one field ends and the next begins).
+fn length_prefixed_message(fields: &[&[u8]]) -> Vec<u8> {
+    let mut message = Vec::new();
+    for field in fields {
+        message.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        message.extend_from_slice(field);
+    }
+    message
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    let state = State {
+        admin: info.sender.clone(),
+    };
+    config(deps.storage).save(&state)?;
+
+    // Seed the per-contract secret used to derive unpredictable liveliness
+    // nonces. Falls back to the contract address if the chain doesn't
+    // supply on-chain randomness at instantiation time.
+    let secret = match &env.block.random {
+        Some(random) => random.0.clone(),
+        None => Sha256::digest(env.contract.address.as_bytes()).to_vec(),
+    };
+    liveliness_secret(deps.storage).save(&secret)?;
+
+    deps.api
+        .debug(format!("Contract was initialized by {}", info.sender).as_str());
+
+    Ok(Response::default())
+}
+
+#[entry_point]
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::RegisterWorker {
+            ip_address,
+            payment_wallet,
+            attestation_report,
+            worker_type,
+        } => try_register_worker(deps, ip_address, payment_wallet, attestation_report, worker_type),
+        ExecuteMsg::SetWorkerWallet {
+            ip_address,
+            payment_wallet,
+        } => try_set_worker_wallet(deps, info, ip_address, payment_wallet),
+        ExecuteMsg::SetWorkerAddress {
+            new_ip_address,
+            old_ip_address,
+        } => try_set_worker_address(deps, info, new_ip_address, old_ip_address),
+        ExecuteMsg::SetWorkerType {
+            ip_address,
+            worker_type,
+        } => try_set_worker_type(deps, info, ip_address, worker_type),
+        ExecuteMsg::RemoveWorker { ip_address } => try_remove_worker(deps, info, ip_address),
+        ExecuteMsg::ReportLiveliness {
+            ip_address,
+            nonce,
+            signature,
+        } => try_report_liveliness(deps, env, ip_address, nonce, signature),
+        ExecuteMsg::ReportWork {} => Ok(Response::new().add_attribute("action", "report_work")),
+        ExecuteMsg::SetAttestationAuthoritySet {
+            index,
+            signers,
+            quorum,
+        } => try_set_attestation_authority_set(deps, info, index, signers, quorum),
+        ExecuteMsg::AllowMeasurement { measurement } => {
+            try_allow_measurement(deps, info, measurement)
+        }
+        ExecuteMsg::AllowSubscriber {
+            subscriber_public_key,
+        } => try_allow_subscriber(deps, info, subscriber_public_key),
+        ExecuteMsg::RegisterSubscriberCallback {
+            callback_address,
+            callback_code_hash,
+        } => try_register_subscriber_callback(deps, callback_address, callback_code_hash),
+        ExecuteMsg::ResendNotifications {} => try_resend_notifications(deps),
+        ExecuteMsg::ResendNotification { id } => try_resend_notification(deps, id),
+        ExecuteMsg::SubmitWorkerVAA { vaa } => try_submit_worker_vaa(deps, vaa.into_vec()),
+    }
+}
+
+/// A self-describing, quorum-signed payload that mirrors one worker-registry
+/// action on another chain so it can be replayed here without a privileged
+/// key on this chain, analogous to a Wormhole VAA.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WorkerVaa {
+    pub emitter_chain: u16,
+    pub sequence: u64,
+    pub action: WorkerVaaAction,
+    pub signatures: Vec<GuardianSignature>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerVaaAction {
+    RegisterWorker {
+        ip_address: String,
+        payment_wallet: Vec<u8>,
+        attestation_report: Vec<u8>,
+        worker_type: WorkerType,
+    },
+    RemoveWorker {
+        ip_address: String,
+    },
+}
+
+pub fn try_submit_worker_vaa(deps: DepsMut, vaa: Vec<u8>) -> StdResult<Response> {
+    let parsed: WorkerVaa = serde_json::from_slice(&vaa)
+        .map_err(|err| StdError::generic_err(format!("Invalid VAA payload: {}", err)))?;
+
+    let sequence_key = format!("{}:{}", parsed.emitter_chain, parsed.sequence);
+    if CONSUMED_VAA_SEQUENCES.contains(deps.storage, &sequence_key) {
+        return Err(StdError::generic_err(
+            "VAA sequence has already been consumed for this emitter chain",
+        ));
+    }
+
+    let authority = authority_set_read(deps.storage)
+        .load()
+        .map_err(|_| StdError::generic_err("No attestation authority set has been configured"))?;
+
+    let canonical_action = serde_json::to_vec(&parsed.action)
+        .map_err(|err| StdError::generic_err(format!("Invalid VAA payload: {}", err)))?;
+    let signed_message = length_prefixed_message(&[
+        &parsed.emitter_chain.to_be_bytes(),
+        &parsed.sequence.to_be_bytes(),
+        &canonical_action,
+    ]);
+    let message_hash = Sha256::digest(&signed_message);
+
+    verify_quorum(deps.as_ref(), &message_hash, &parsed.signatures, &authority)?;
+
+    CONSUMED_VAA_SEQUENCES
+        .insert(deps.storage, &sequence_key, &())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let highest = HIGHEST_VAA_SEQUENCE
+        .get(deps.storage, &parsed.emitter_chain)
+        .unwrap_or_default();
+    if parsed.sequence > highest {
+        HIGHEST_VAA_SEQUENCE
+            .insert(deps.storage, &parsed.emitter_chain, &parsed.sequence)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+    }
+
+    let mut response = match parsed.action {
+        WorkerVaaAction::RegisterWorker {
+            ip_address,
+            payment_wallet,
+            attestation_report,
+            worker_type,
+        } => try_register_worker(
+            deps,
+            ip_address,
+            EncodedBytes(payment_wallet),
+            EncodedBytes(attestation_report),
+            worker_type,
+        )?,
+        WorkerVaaAction::RemoveWorker { ip_address } => remove_worker(deps, ip_address)?,
+    };
+    response = response
+        .add_attribute("emitter_chain", parsed.emitter_chain.to_string())
+        .add_attribute("sequence", parsed.sequence.to_string());
+
+    Ok(response)
+}
+
+pub fn try_register_subscriber_callback(
+    deps: DepsMut,
+    callback_address: String,
+    callback_code_hash: String,
+) -> StdResult<Response> {
+    let addr = deps.api.addr_validate(&callback_address)?;
+    SUBSCRIBER_CALLBACKS
+        .insert(
+            deps.storage,
+            &callback_address,
+            &SubscriberCallback {
+                address: addr,
+                code_hash: callback_code_hash,
+            },
+        )
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_subscriber_callback")
+        .add_attribute("callback_address", callback_address))
+}
+
+/// Builds one `SubMsg` per registered subscriber for `event`, persisting a
+/// `Pending` `Notification` for each so the reply handler can flip it to
+/// `Delivered`/`Failed`.
+fn notify_subscribers(deps: DepsMut, event: NotificationEvent) -> StdResult<Vec<SubMsg>> {
+    let callbacks: Vec<SubscriberCallback> = SUBSCRIBER_CALLBACKS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(_, callback)| callback)
+        .collect();
+
+    let mut sub_msgs = Vec::with_capacity(callbacks.len());
+    for callback in callbacks {
+        let id = next_notification_id(deps.storage)?;
+        NOTIFICATIONS
+            .insert(
+                deps.storage,
+                &id,
+                &Notification {
+                    id,
+                    callback_address: callback.address.clone(),
+                    callback_code_hash: callback.code_hash.clone(),
+                    event: event.clone(),
+                    status: NotificationStatus::Pending,
+                },
+            )
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+        sub_msgs.push(notification_sub_msg(
+            id,
+            &callback.address,
+            &callback.code_hash,
+            &event,
+        )?);
+    }
+
+    Ok(sub_msgs)
+}
+
+fn notification_sub_msg(
+    id: u64,
+    callback_address: &Addr,
+    callback_code_hash: &str,
+    event: &NotificationEvent,
+) -> StdResult<SubMsg> {
+    let wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: callback_address.to_string(),
+        code_hash: callback_code_hash.to_string(),
+        msg: to_binary(event)?,
+        funds: vec![],
+    });
+    Ok(SubMsg::reply_always(wasm_msg, id))
+}
+
+pub fn try_resend_notifications(deps: DepsMut) -> StdResult<Response> {
+    let failed: Vec<Notification> = NOTIFICATIONS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(_, notification)| notification)
+        .filter(|notification| notification.status == NotificationStatus::Failed)
+        .collect();
+
+    let mut sub_msgs = Vec::with_capacity(failed.len());
+    for notification in failed {
+        sub_msgs.push(notification_sub_msg(
+            notification.id,
+            &notification.callback_address,
+            &notification.callback_code_hash,
+            &notification.event,
+        )?);
+
+        let mut pending = notification;
+        pending.status = NotificationStatus::Pending;
+        NOTIFICATIONS
+            .insert(deps.storage, &pending.id, &pending)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "resend_notifications")
+        .add_attribute("count", sub_msgs.len().to_string())
+        .add_submessages(sub_msgs))
+}
+
+pub fn try_resend_notification(deps: DepsMut, id: u64) -> StdResult<Response> {
+    let mut notification = NOTIFICATIONS
+        .get(deps.storage, &id)
+        .ok_or_else(|| StdError::generic_err("Notification not found"))?;
+
+    let sub_msg = notification_sub_msg(
+        id,
+        &notification.callback_address,
+        &notification.callback_code_hash,
+        &notification.event,
+    )?;
+
+    notification.status = NotificationStatus::Pending;
+    NOTIFICATIONS
+        .insert(deps.storage, &id, &notification)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "resend_notification")
+        .add_attribute("id", id.to_string())
+        .add_submessage(sub_msg))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    let id = msg.id;
+    let mut notification = NOTIFICATIONS
+        .get(deps.storage, &id)
+        .ok_or_else(|| StdError::generic_err("Reply for unknown notification"))?;
+
+    notification.status = match msg.result {
+        cosmwasm_std::SubMsgResult::Ok(_) => NotificationStatus::Delivered,
+        cosmwasm_std::SubMsgResult::Err(_) => NotificationStatus::Failed,
+    };
+    NOTIFICATIONS
+        .insert(deps.storage, &id, &notification)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new().add_attribute("action", "notification_reply"))
+}
+
+fn require_admin(deps: &DepsMut, info: &MessageInfo) -> StdResult<()> {
+    let state = config_read(deps.storage).load()?;
+    if info.sender != state.admin {
+        return Err(StdError::generic_err("Only admin can perform this action"));
+    }
+    Ok(())
+}
+
+pub fn try_set_attestation_authority_set(
+    deps: DepsMut,
+    info: MessageInfo,
+    index: u32,
+    signers: Vec<Vec<u8>>,
+    quorum: u32,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    if quorum == 0 || quorum as usize > signers.len() {
+        return Err(StdError::generic_err(
+            "Quorum must be between 1 and the number of signers",
+        ));
+    }
+
+    let current_index = authority_set_read(deps.storage)
+        .may_load()?
+        .map(|authority| authority.index);
+    let expected_index = current_index.map_or(0, |index| index + 1);
+    if index != expected_index {
+        return Err(StdError::generic_err(format!(
+            "index must be exactly one greater than the currently stored index; expected {}",
+            expected_index
+        )));
+    }
+
+    authority_set(deps.storage).save(&AttestationAuthoritySet {
+        index,
+        signers,
+        quorum,
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_attestation_authority_set")
+        .add_attribute("index", index.to_string()))
+}
+
+pub fn try_allow_measurement(
+    deps: DepsMut,
+    info: MessageInfo,
+    measurement: EncodedBytes,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    ALLOWED_MEASUREMENTS
+        .insert(deps.storage, &measurement.into_vec(), &())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new().add_attribute("action", "allow_measurement"))
+}
+
+pub fn try_allow_subscriber(
+    deps: DepsMut,
+    info: MessageInfo,
+    subscriber_public_key: EncodedBytes,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    ALLOWED_SUBSCRIBERS
+        .insert(deps.storage, &subscriber_public_key.into_vec(), &())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new().add_attribute("action", "allow_subscriber"))
+}
+
+/// Decodes `report` and checks it against the current authority set and
+/// measurement allow-list without touching storage beyond reads. Shared by
+/// `try_register_worker` and `QueryMsg::VerifyAttestation`.
+fn verify_attestation_report(
+    deps: Deps,
+    report: &[u8],
+    ip_address: &str,
+) -> StdResult<AttestationReport> {
+    let decoded = AttestationReport::decode(report)?;
+
+    let authority = authority_set_read(deps.storage).load().map_err(|_| {
+        StdError::generic_err("No attestation authority set has been configured")
+    })?;
+
+    if !ALLOWED_MEASUREMENTS.contains(deps.storage, &decoded.measurement) {
+        return Err(StdError::generic_err(
+            "Enclave measurement is not in the allow-list",
+        ));
+    }
+
+    let signed_message = length_prefixed_message(&[
+        &decoded.measurement,
+        &decoded.reported_pubkey,
+        ip_address.as_bytes(),
+    ]);
+    let message_hash = Sha256::digest(&signed_message);
+
+    verify_quorum(deps, &message_hash, &decoded.signatures, &authority)?;
+
+    Ok(decoded)
+}
+
+/// Recovers each signer in `signatures` over `message_hash` and checks that
+/// at least `authority.quorum` distinct, recognized signers are present.
+/// Shared between attestation-report verification and cross-chain VAA
+/// verification, both of which trust the same guardian-set-style authority.
+fn verify_quorum(
+    deps: Deps,
+    message_hash: &[u8],
+    signatures: &[GuardianSignature],
+    authority: &AttestationAuthoritySet,
+) -> StdResult<()> {
+    let mut distinct_signers: Vec<u32> = Vec::new();
+    for sig in signatures {
+        let signer = authority
+            .signers
+            .get(sig.signer_index as usize)
+            .ok_or_else(|| StdError::generic_err("Signature references unknown signer index"))?;
+
+        let recovered = deps
+            .api
+            .secp256k1_recover_pubkey(message_hash, &sig.signature, sig.recovery_id)
+            .map_err(|err| StdError::generic_err(format!("Failed to recover signer: {}", err)))?;
+
+        if &recovered != signer {
+            return Err(StdError::generic_err(
+                "Recovered signer does not match the authority set entry",
+            ));
+        }
+
+        if !distinct_signers.contains(&sig.signer_index) {
+            distinct_signers.push(sig.signer_index);
+        }
+    }
+
+    if (distinct_signers.len() as u32) < authority.quorum {
+        return Err(StdError::generic_err(
+            "Not signed by enough authority members to reach quorum",
+        ));
+    }
+
+    Ok(())
+}
+
+impl AttestationReport {
+    /// `report` is the already-decoded payload (`EncodedBytes` accepts
+    /// base64 in any flavor or hex on the wire) containing JSON.
+    fn decode(report: &[u8]) -> StdResult<Self> {
+        serde_json::from_slice(report)
+            .map_err(|err| StdError::generic_err(format!("Invalid attestation report: {}", err)))
+    }
+}
+
+pub fn try_register_worker(
+    deps: DepsMut,
+    ip_address: String,
+    payment_wallet: EncodedBytes,
+    attestation_report: EncodedBytes,
+    worker_type: WorkerType,
+) -> StdResult<Response> {
+    if WORKERS.contains(deps.storage, &ip_address) {
+        return Err(StdError::generic_err("Worker already registered"));
+    }
+
+    let decoded =
+        verify_attestation_report(deps.as_ref(), attestation_report.as_slice(), &ip_address)?;
+
+    let worker = Worker {
+        ip_address: ip_address.clone(),
+        payment_wallet: payment_wallet.into_vec(),
+        attestation_report: attestation_report.into_vec(),
+        worker_type,
+        measurement: decoded.measurement,
+        reported_pubkey: decoded.reported_pubkey,
+        last_liveliness: 0,
+        last_nonce: String::new(),
+    };
+    WORKERS
+        .insert(deps.storage, &ip_address, &worker)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let sub_msgs = notify_subscribers(
+        deps,
+        NotificationEvent::WorkerRegistered {
+            ip_address: ip_address.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_worker")
+        .add_attribute("ip_address", ip_address)
+        .add_submessages(sub_msgs))
+}
+
+pub fn try_set_worker_wallet(
+    deps: DepsMut,
+    info: MessageInfo,
+    ip_address: String,
+    payment_wallet: EncodedBytes,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    let mut worker = WORKERS
+        .get(deps.storage, &ip_address)
+        .ok_or_else(|| StdError::generic_err("Worker not found"))?;
+
+    worker.payment_wallet = payment_wallet.into_vec();
+    WORKERS
+        .insert(deps.storage, &ip_address, &worker)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_worker_wallet")
+        .add_attribute("ip_address", ip_address))
+}
+
+pub fn try_set_worker_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_ip_address: String,
+    old_ip_address: String,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    let mut worker = WORKERS
+        .get(deps.storage, &old_ip_address)
+        .ok_or_else(|| StdError::generic_err("Worker not found"))?;
+
+    if WORKERS.contains(deps.storage, &new_ip_address) {
+        return Err(StdError::generic_err("A worker is already registered at the new address"));
+    }
+
+    worker.ip_address = new_ip_address.clone();
+    WORKERS
+        .insert(deps.storage, &new_ip_address, &worker)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    WORKERS
+        .remove(deps.storage, &old_ip_address)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_worker_address")
+        .add_attribute("old_ip_address", old_ip_address)
+        .add_attribute("new_ip_address", new_ip_address))
+}
+
+pub fn try_set_worker_type(
+    deps: DepsMut,
+    info: MessageInfo,
+    ip_address: String,
+    worker_type: WorkerType,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+
+    let mut worker = WORKERS
+        .get(deps.storage, &ip_address)
+        .ok_or_else(|| StdError::generic_err("Worker not found"))?;
+
+    worker.worker_type = worker_type;
+    WORKERS
+        .insert(deps.storage, &ip_address, &worker)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_worker_type")
+        .add_attribute("ip_address", ip_address))
+}
+
+pub fn try_remove_worker(
+    deps: DepsMut,
+    info: MessageInfo,
+    ip_address: String,
+) -> StdResult<Response> {
+    require_admin(&deps, &info)?;
+    remove_worker(deps, ip_address)
+}
+
+/// The actual removal, shared between the admin-gated `RemoveWorker` and
+/// `try_submit_worker_vaa`'s `RemoveWorker` action, which is authorized by
+/// the VAA's quorum signature instead of an admin `MessageInfo`.
+fn remove_worker(deps: DepsMut, ip_address: String) -> StdResult<Response> {
+    if !WORKERS.contains(deps.storage, &ip_address) {
+        return Err(StdError::generic_err("Worker not found"));
+    }
+
+    WORKERS
+        .remove(deps.storage, &ip_address)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    let sub_msgs = notify_subscribers(
+        deps,
+        NotificationEvent::WorkerRemoved {
+            ip_address: ip_address.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_worker")
+        .add_attribute("ip_address", ip_address)
+        .add_submessages(sub_msgs))
+}
+
+/// Derives the liveliness challenge nonce (and its expiry) for `ip_address`
+/// in the epoch containing `now`. Deterministic within an epoch so a query
+/// and a later execute in the same window agree on the nonce, but
+/// unpredictable off-chain since it's mixed with the contract secret.
+fn liveliness_nonce(deps: Deps, ip_address: &str, now: u64) -> StdResult<(String, u64)> {
+    let secret = liveliness_secret_read(deps.storage)
+        .load()
+        .map_err(|_| StdError::generic_err("Liveliness secret has not been initialized"))?;
+    let epoch = now / LIVELINESS_EPOCH_SECONDS;
+
+    let mut preimage = secret;
+    preimage.extend_from_slice(ip_address.as_bytes());
+    preimage.extend_from_slice(&epoch.to_be_bytes());
+    let nonce = hex::encode(Sha256::digest(&preimage));
+
+    let expires_at = (epoch + 1) * LIVELINESS_EPOCH_SECONDS;
+    Ok((nonce, expires_at))
+}
+
+pub fn try_report_liveliness(
+    deps: DepsMut,
+    env: Env,
+    ip_address: String,
+    nonce: String,
+    signature: EncodedBytes,
+) -> StdResult<Response> {
+    let mut worker = WORKERS
+        .get(deps.storage, &ip_address)
+        .ok_or_else(|| StdError::generic_err("Worker not found"))?;
+
+    let now = env.block.time.seconds();
+    let (expected_nonce, expires_at) = liveliness_nonce(deps.as_ref(), &ip_address, now)?;
+
+    if nonce != expected_nonce {
+        return Err(StdError::generic_err("Nonce is invalid or expired"));
+    }
+    if now >= expires_at {
+        return Err(StdError::generic_err("Nonce is invalid or expired"));
+    }
+    if nonce == worker.last_nonce {
+        return Err(StdError::generic_err("Nonce has already been used"));
+    }
+
+    let nonce_hash = Sha256::digest(nonce.as_bytes());
+    deps.api
+        .secp256k1_verify(&nonce_hash, signature.as_slice(), &worker.reported_pubkey)
+        .map_err(|err| StdError::generic_err(format!("Signature verification failed: {}", err)))
+        .and_then(|valid| {
+            if valid {
+                Ok(())
+            } else {
+                Err(StdError::generic_err("Signature does not match worker's registered key"))
+            }
+        })?;
+
+    let was_live = now.saturating_sub(worker.last_liveliness) <= LIVELINESS_STALENESS_SECONDS;
+
+    worker.last_nonce = nonce;
+    worker.last_liveliness = now;
+    WORKERS
+        .insert(deps.storage, &ip_address, &worker)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    // Only notify on an actual dead -> live transition, not every check-in.
+    let sub_msgs = if !was_live {
+        notify_subscribers(
+            deps,
+            NotificationEvent::LivelinessChanged {
+                ip_address: ip_address.clone(),
+                is_live: true,
+            },
+        )?
+    } else {
+        vec![]
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "report_liveliness")
+        .add_attribute("ip_address", ip_address)
+        .add_submessages(sub_msgs))
+}
+
+/// Checks that `subscriber_public_key` is allow-listed (via
+/// `AllowSubscriber`) and that `signature` is a valid secp256k1 signature by
+/// that key over the current epoch's `GetWorkers` challenge, proving the
+/// caller holds the matching private key. Epoch-bucketed like
+/// `liveliness_nonce`, so the caller can compute and sign the challenge
+/// itself without a prior round trip.
+fn verify_subscriber_auth(
+    deps: Deps,
+    env: &Env,
+    signature: &EncodedBytes,
+    subscriber_public_key: &EncodedBytes,
+) -> StdResult<()> {
+    let pubkey = subscriber_public_key.as_slice();
+    if !ALLOWED_SUBSCRIBERS.contains(deps.storage, &pubkey.to_vec()) {
+        return Err(StdError::generic_err(
+            "Subscriber public key is not allow-listed",
+        ));
+    }
+
+    let epoch = env.block.time.seconds() / LIVELINESS_EPOCH_SECONDS;
+    let message_hash = Sha256::digest(format!("get_workers:{}", epoch).as_bytes());
+
+    let valid = deps
+        .api
+        .secp256k1_verify(&message_hash, signature.as_slice(), pubkey)
+        .map_err(|err| StdError::generic_err(format!("Signature verification failed: {}", err)))?;
+    if !valid {
+        return Err(StdError::generic_err(
+            "Signature does not match subscriber's public key",
+        ));
+    }
+    Ok(())
+}
+
+#[entry_point]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetWorkers {
+            signature,
+            subscriber_public_key,
+            start_after,
+            limit,
+            filter,
+        } => {
+            verify_subscriber_auth(deps, &env, &signature, &subscriber_public_key)?;
+            to_binary(&query_workers(deps, env.clone(), start_after, limit, filter)?)
+        }
+        QueryMsg::GetLivelinessChallenge { ip_address } => {
+            to_binary(&query_liveliness_challenge(deps, env, ip_address)?)
+        }
+        QueryMsg::GetWorkerLiveliness { ip_address } => {
+            to_binary(&query_worker_liveliness(deps, env, ip_address)?)
+        }
+        QueryMsg::VerifyAttestation { report, ip_address } => {
+            to_binary(&query_verify_attestation(deps, report, ip_address)?)
+        }
+        QueryMsg::GetFailedNotifications { start_after, limit } => {
+            to_binary(&query_failed_notifications(deps, start_after, limit)?)
+        }
+        QueryMsg::GetState {} => to_binary(&query_state(deps)?),
+    }
+}
+
+fn query_state(deps: Deps) -> StdResult<crate::msg::GetStateResponse> {
+    let authority_set_index = authority_set_read(deps.storage)
+        .may_load()?
+        .map(|authority| authority.index)
+        .unwrap_or_default();
+
+    let highest_consumed_sequence: Vec<crate::msg::EmitterSequence> = HIGHEST_VAA_SEQUENCE
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(emitter_chain, highest_sequence)| crate::msg::EmitterSequence {
+            emitter_chain,
+            highest_sequence,
+        })
+        .collect();
+
+    Ok(crate::msg::GetStateResponse {
+        authority_set_index,
+        highest_consumed_sequence,
+    })
+}
+
+const DEFAULT_NOTIFICATIONS_LIMIT: u32 = 30;
+const MAX_NOTIFICATIONS_LIMIT: u32 = 100;
+
+fn query_failed_notifications(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<crate::msg::GetFailedNotificationsResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_NOTIFICATIONS_LIMIT)
+        .min(MAX_NOTIFICATIONS_LIMIT) as usize;
+
+    let mut failed: Vec<Notification> = NOTIFICATIONS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(_, notification)| notification)
+        .filter(|notification| notification.status == NotificationStatus::Failed)
+        .filter(|notification| start_after.map_or(true, |after| notification.id > after))
+        .collect();
+    failed.sort_by_key(|notification| notification.id);
+
+    let next_key = if failed.len() > limit {
+        failed.truncate(limit);
+        failed.last().map(|notification| notification.id)
+    } else {
+        None
+    };
+
+    Ok(crate::msg::GetFailedNotificationsResponse {
+        notifications: failed,
+        next_key,
+    })
+}
+
+fn query_liveliness_challenge(
+    deps: Deps,
+    env: Env,
+    ip_address: String,
+) -> StdResult<GetLivelinessChallengeResponse> {
+    let (nonce, expires_at) = liveliness_nonce(deps, &ip_address, env.block.time.seconds())?;
+    Ok(GetLivelinessChallengeResponse { nonce, expires_at })
+}
+
+fn query_worker_liveliness(
+    deps: Deps,
+    env: Env,
+    ip_address: String,
+) -> StdResult<GetWorkerLivelinessResponse> {
+    let worker = WORKERS
+        .get(deps.storage, &ip_address)
+        .ok_or_else(|| StdError::generic_err("Worker not found"))?;
+
+    let is_live = env.block.time.seconds().saturating_sub(worker.last_liveliness)
+        <= LIVELINESS_STALENESS_SECONDS;
+
+    Ok(GetWorkerLivelinessResponse {
+        last_liveliness: worker.last_liveliness,
+        is_live,
+    })
+}
+
+const DEFAULT_WORKERS_LIMIT: u32 = 30;
+const MAX_WORKERS_LIMIT: u32 = 100;
+
+fn query_workers(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    filter: Option<crate::msg::WorkerFilter>,
+) -> StdResult<GetWorkersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_WORKERS_LIMIT).min(MAX_WORKERS_LIMIT) as usize;
+    let now = env.block.time.seconds();
+
+    let mut workers: Vec<Worker> = WORKERS
+        .iter(deps.storage)?
+        .filter_map(|entry| entry.ok())
+        .map(|(_, worker)| worker)
+        .filter(|worker| {
+            start_after
+                .as_ref()
+                .map_or(true, |after| worker.ip_address.as_str() > after.as_str())
+        })
+        .filter(|worker| match &filter {
+            Some(filter) => {
+                let type_matches = filter
+                    .worker_type
+                    .as_ref()
+                    .map_or(true, |wt| wt == &worker.worker_type);
+                let freshness_matches = filter.max_liveliness_age.map_or(true, |max_age| {
+                    now.saturating_sub(worker.last_liveliness) <= max_age
+                });
+                type_matches && freshness_matches
+            }
+            None => true,
+        })
+        .collect();
+    workers.sort_by(|a, b| a.ip_address.cmp(&b.ip_address));
+
+    let next_key = if workers.len() > limit {
+        workers.truncate(limit);
+        workers.last().map(|worker| worker.ip_address.clone())
+    } else {
+        None
+    };
+
+    Ok(GetWorkersResponse { workers, next_key })
+}
+
+fn query_verify_attestation(
+    deps: Deps,
+    report: EncodedBytes,
+    ip_address: String,
+) -> StdResult<VerifyAttestationResponse> {
+    match AttestationReport::decode(report.as_slice()) {
+        Ok(decoded) => {
+            let valid = verify_attestation_report(deps, report.as_slice(), &ip_address).is_ok();
+            Ok(VerifyAttestationResponse {
+                measurement: decoded.measurement,
+                reported_pubkey: decoded.reported_pubkey,
+                valid,
+            })
+        }
+        Err(_) => Ok(VerifyAttestationResponse {
+            measurement: vec![],
+            reported_pubkey: vec![],
+            valid: false,
+        }),
+    }
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    match msg {
+        MigrateMsg::Migrate {} => Ok(Response::new().add_attribute("action", "migrate")),
+        MigrateMsg::StdError {} => Err(StdError::generic_err("this is an std error")),
+        MigrateMsg::SeedAuthoritySet { signers, quorum } => {
+            if authority_set_read(deps.storage).load().is_ok() {
+                return Err(StdError::generic_err(
+                    "Attestation authority set has already been seeded",
+                ));
+            }
+
+            if quorum == 0 || quorum as usize > signers.len() {
+                return Err(StdError::generic_err(
+                    "Quorum must be between 1 and the number of signers",
+                ));
+            }
+
+            authority_set(deps.storage).save(&AttestationAuthoritySet {
+                index: 0,
+                signers,
+                quorum,
+            })?;
+
+            Ok(Response::new().add_attribute("action", "seed_authority_set"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::from_binary;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use k256::ecdsa::{RecoveryId, Signature, SigningKey};
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    use crate::msg::WorkerFilter;
+    use crate::state::CONSUMED_VAA_SEQUENCES;
+
+    /// A deterministic (seed-derived) secp256k1 keypair for signing test
+    /// attestation reports, VAAs, and liveliness/subscriber challenges.
+    fn keypair(seed: u8) -> (SigningKey, Vec<u8>) {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 1;
+        bytes[31] = seed;
+        let signing_key = SigningKey::from_bytes(&bytes.into()).unwrap();
+        let compressed = signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        (signing_key, compressed)
+    }
+
+    fn sign_recoverable(signing_key: &SigningKey, message_hash: &[u8]) -> (Vec<u8>, u8) {
+        let (sig, recid): (Signature, RecoveryId) =
+            signing_key.sign_prehash_recoverable(message_hash).unwrap();
+        (sig.to_bytes().to_vec(), recid.to_byte())
+    }
+
+    fn seed_authority(deps: DepsMut, signers: Vec<Vec<u8>>, quorum: u32) {
+        authority_set(deps.storage)
+            .save(&AttestationAuthoritySet {
+                index: 0,
+                signers,
+                quorum,
+            })
+            .unwrap();
+    }
+
+    fn attestation_report(
+        measurement: &[u8],
+        reported_pubkey: &[u8],
+        ip_address: &str,
+        signers: &[(&SigningKey, u32)],
+    ) -> Vec<u8> {
+        let signed_message =
+            length_prefixed_message(&[measurement, reported_pubkey, ip_address.as_bytes()]);
+        let message_hash = Sha256::digest(&signed_message);
+
+        let signatures: Vec<GuardianSignature> = signers
+            .iter()
+            .map(|(key, signer_index)| {
+                let (signature, recovery_id) = sign_recoverable(key, &message_hash);
+                GuardianSignature {
+                    signer_index: *signer_index,
+                    signature,
+                    recovery_id,
+                }
+            })
+            .collect();
+
+        serde_json::to_vec(&AttestationReport {
+            measurement: measurement.to_vec(),
+            reported_pubkey: reported_pubkey.to_vec(),
+            signatures,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    /// A worker can't register with a measurement that hasn't been
+    /// allow-listed, and can once it has.
+    fn measurement_allow_listing_gates_registration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env.clone(), admin.clone(), InstantiateMsg {}).unwrap();
+
+        let (signing_key, pubkey) = keypair(1);
+        seed_authority(deps.as_mut(), vec![pubkey], 1);
+
+        let measurement = b"mrenclave".to_vec();
+        let reported_pubkey = b"worker-pubkey".to_vec();
+        let report = attestation_report(
+            &measurement,
+            &reported_pubkey,
+            "1.2.3.4",
+            &[(&signing_key, 0)],
+        );
+
+        let err = try_register_worker(
+            deps.as_mut(),
+            "1.2.3.4".to_string(),
+            EncodedBytes(b"wallet".to_vec()),
+            EncodedBytes(report.clone()),
+            WorkerType::Cpu,
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        try_allow_measurement(deps.as_mut(), admin, EncodedBytes(measurement)).unwrap();
+
+        try_register_worker(
+            deps.as_mut(),
+            "1.2.3.4".to_string(),
+            EncodedBytes(b"wallet".to_vec()),
+            EncodedBytes(report),
+            WorkerType::Cpu,
+        )
+        .unwrap();
+        assert!(WORKERS.contains(deps.as_ref().storage, &"1.2.3.4".to_string()));
+    }
+
+    #[test]
+    /// Registration requires quorum-many distinct, recognized signers over
+    /// the attestation report; one signature out of a 2-of-2 quorum isn't
+    /// enough.
+    fn registration_enforces_attestation_quorum() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env, admin.clone(), InstantiateMsg {}).unwrap();
+
+        let (key_a, pubkey_a) = keypair(1);
+        let (key_b, pubkey_b) = keypair(2);
+        seed_authority(deps.as_mut(), vec![pubkey_a, pubkey_b], 2);
+
+        let measurement = b"mrenclave".to_vec();
+        try_allow_measurement(deps.as_mut(), admin, EncodedBytes(measurement.clone())).unwrap();
+
+        let reported_pubkey = b"worker-pubkey".to_vec();
+        let under_quorum_report = attestation_report(
+            &measurement,
+            &reported_pubkey,
+            "1.2.3.4",
+            &[(&key_a, 0)],
+        );
+        let err = try_register_worker(
+            deps.as_mut(),
+            "1.2.3.4".to_string(),
+            EncodedBytes(b"wallet".to_vec()),
+            EncodedBytes(under_quorum_report),
+            WorkerType::Cpu,
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let full_quorum_report = attestation_report(
+            &measurement,
+            &reported_pubkey,
+            "1.2.3.4",
+            &[(&key_a, 0), (&key_b, 1)],
+        );
+        try_register_worker(
+            deps.as_mut(),
+            "1.2.3.4".to_string(),
+            EncodedBytes(b"wallet".to_vec()),
+            EncodedBytes(full_quorum_report),
+            WorkerType::Cpu,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    /// A liveliness nonce can't be replayed once it has been successfully
+    /// used, even if it's still within its epoch window.
+    fn liveliness_nonce_cannot_be_replayed() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env.clone(), admin.clone(), InstantiateMsg {}).unwrap();
+
+        let (worker_key, worker_pubkey) = keypair(3);
+        let (guardian_key, guardian_pubkey) = keypair(1);
+        seed_authority(deps.as_mut(), vec![guardian_pubkey], 1);
+        let measurement = b"mrenclave".to_vec();
+        try_allow_measurement(deps.as_mut(), admin, EncodedBytes(measurement.clone())).unwrap();
+
+        let report = attestation_report(
+            &measurement,
+            &worker_pubkey,
+            "1.2.3.4",
+            &[(&guardian_key, 0)],
+        );
+        try_register_worker(
+            deps.as_mut(),
+            "1.2.3.4".to_string(),
+            EncodedBytes(b"wallet".to_vec()),
+            EncodedBytes(report),
+            WorkerType::Cpu,
+        )
+        .unwrap();
+
+        let (nonce, _) = liveliness_nonce(deps.as_ref(), "1.2.3.4", env.block.time.seconds()).unwrap();
+        let nonce_hash = Sha256::digest(nonce.as_bytes());
+        let (signature, _) = sign_recoverable(&worker_key, &nonce_hash);
+
+        try_report_liveliness(
+            deps.as_mut(),
+            env.clone(),
+            "1.2.3.4".to_string(),
+            nonce.clone(),
+            EncodedBytes(signature.clone()),
+        )
+        .unwrap();
+
+        let err = try_report_liveliness(
+            deps.as_mut(),
+            env,
+            "1.2.3.4".to_string(),
+            nonce,
+            EncodedBytes(signature),
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `SubmitWorkerVAA` rejects a sequence that has already been consumed
+    /// for its emitter chain, regardless of whether the signatures would
+    /// otherwise verify.
+    fn vaa_replay_is_rejected() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env, admin, InstantiateMsg {}).unwrap();
+
+        CONSUMED_VAA_SEQUENCES
+            .insert(deps.as_mut().storage, &"5:42".to_string(), &())
+            .unwrap();
+
+        let vaa = WorkerVaa {
+            emitter_chain: 5,
+            sequence: 42,
+            action: WorkerVaaAction::RemoveWorker {
+                ip_address: "1.2.3.4".to_string(),
+            },
+            signatures: vec![],
+        };
+        let err = try_submit_worker_vaa(deps.as_mut(), serde_json::to_vec(&vaa).unwrap())
+            .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+    }
+
+    #[test]
+    /// `SetAttestationAuthoritySet` rejects an index that isn't exactly one
+    /// greater than the currently stored index.
+    fn set_attestation_authority_set_enforces_monotonic_index() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env, admin.clone(), InstantiateMsg {}).unwrap();
+
+        let (_, pubkey) = keypair(1);
+
+        let err = try_set_attestation_authority_set(
+            deps.as_mut(),
+            admin.clone(),
+            5,
+            vec![pubkey.clone()],
+            1,
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        try_set_attestation_authority_set(deps.as_mut(), admin.clone(), 0, vec![pubkey.clone()], 1)
+            .unwrap();
+
+        let err = try_set_attestation_authority_set(deps.as_mut(), admin.clone(), 0, vec![pubkey.clone()], 1)
+            .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        try_set_attestation_authority_set(deps.as_mut(), admin, 1, vec![pubkey], 1).unwrap();
+    }
+
+    #[test]
+    /// `GetWorkers` rejects a subscriber public key that hasn't been
+    /// allow-listed, and a signature that doesn't match the claimed key, but
+    /// succeeds (with pagination/filtering applied) for an allow-listed
+    /// subscriber with a valid signature.
+    fn get_workers_requires_allow_listed_subscriber_signature() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env.clone(), admin.clone(), InstantiateMsg {}).unwrap();
+
+        WORKERS
+            .insert(
+                deps.as_mut().storage,
+                &"1.2.3.4".to_string(),
+                &Worker {
+                    ip_address: "1.2.3.4".to_string(),
+                    payment_wallet: vec![],
+                    attestation_report: vec![],
+                    worker_type: WorkerType::Cpu,
+                    measurement: vec![],
+                    reported_pubkey: vec![],
+                    last_liveliness: 0,
+                    last_nonce: String::new(),
+                },
+            )
+            .unwrap();
+        WORKERS
+            .insert(
+                deps.as_mut().storage,
+                &"5.6.7.8".to_string(),
+                &Worker {
+                    ip_address: "5.6.7.8".to_string(),
+                    payment_wallet: vec![],
+                    attestation_report: vec![],
+                    worker_type: WorkerType::Gpu,
+                    measurement: vec![],
+                    reported_pubkey: vec![],
+                    last_liveliness: 0,
+                    last_nonce: String::new(),
+                },
+            )
+            .unwrap();
+
+        let (subscriber_key, subscriber_pubkey) = keypair(9);
+        let epoch = env.block.time.seconds() / LIVELINESS_EPOCH_SECONDS;
+        let message_hash = Sha256::digest(format!("get_workers:{}", epoch).as_bytes());
+        let (signature, _) = sign_recoverable(&subscriber_key, &message_hash);
+
+        // Not yet allow-listed.
+        let err = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetWorkers {
+                signature: EncodedBytes(signature.clone()),
+                subscriber_public_key: EncodedBytes(subscriber_pubkey.clone()),
+                start_after: None,
+                limit: None,
+                filter: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        try_allow_subscriber(deps.as_mut(), admin, EncodedBytes(subscriber_pubkey.clone())).unwrap();
+
+        // Wrong signature for the allow-listed key.
+        let (other_key, _) = keypair(10);
+        let (wrong_signature, _) = sign_recoverable(&other_key, &message_hash);
+        let err = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetWorkers {
+                signature: EncodedBytes(wrong_signature),
+                subscriber_public_key: EncodedBytes(subscriber_pubkey.clone()),
+                start_after: None,
+                limit: None,
+                filter: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::GetWorkers {
+                signature: EncodedBytes(signature),
+                subscriber_public_key: EncodedBytes(subscriber_pubkey),
+                start_after: None,
+                limit: None,
+                filter: Some(WorkerFilter {
+                    worker_type: Some(WorkerType::Gpu),
+                    max_liveliness_age: None,
+                }),
+            },
+        )
+        .unwrap();
+        let response: GetWorkersResponse = from_binary(&bin).unwrap();
+        assert_eq!(response.workers.len(), 1);
+        assert_eq!(response.workers[0].ip_address, "5.6.7.8");
+    }
+
+    #[test]
+    /// `VerifyAttestation` must actually check the guardian signatures, not
+    /// just the measurement allow-list — a report with an allow-listed
+    /// measurement but no quorum-valid signatures must come back
+    /// `valid: false`, and only a properly signed report comes back `true`.
+    fn verify_attestation_requires_signature_quorum() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env.clone(), admin.clone(), InstantiateMsg {}).unwrap();
+
+        let (signing_key, pubkey) = keypair(1);
+        seed_authority(deps.as_mut(), vec![pubkey], 1);
+
+        let measurement = b"mrenclave".to_vec();
+        try_allow_measurement(deps.as_mut(), admin, EncodedBytes(measurement.clone())).unwrap();
+
+        let reported_pubkey = b"worker-pubkey".to_vec();
+        let unsigned_report = attestation_report(&measurement, &reported_pubkey, "1.2.3.4", &[]);
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::VerifyAttestation {
+                report: EncodedBytes(unsigned_report),
+                ip_address: "1.2.3.4".to_string(),
+            },
+        )
+        .unwrap();
+        let response: VerifyAttestationResponse = from_binary(&bin).unwrap();
+        assert!(!response.valid);
+
+        let signed_report = attestation_report(
+            &measurement,
+            &reported_pubkey,
+            "1.2.3.4",
+            &[(&signing_key, 0)],
+        );
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::VerifyAttestation {
+                report: EncodedBytes(signed_report),
+                ip_address: "1.2.3.4".to_string(),
+            },
+        )
+        .unwrap();
+        let response: VerifyAttestationResponse = from_binary(&bin).unwrap();
+        assert!(response.valid);
+    }
+
+    #[test]
+    /// A `Failed` notification shows up in `GetFailedNotifications`, can be
+    /// resent (flipping it back to `Pending`), and the `reply` entry point
+    /// then transitions it to `Delivered` or `Failed` depending on whether
+    /// the sub-message succeeded.
+    fn failed_notifications_resend_and_reply_transition_status() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env.clone(), admin, InstantiateMsg {}).unwrap();
+
+        NOTIFICATIONS
+            .insert(
+                deps.as_mut().storage,
+                &1,
+                &Notification {
+                    id: 1,
+                    callback_address: Addr::unchecked("subscriber"),
+                    callback_code_hash: "codehash".to_string(),
+                    event: NotificationEvent::WorkerRemoved {
+                        ip_address: "1.2.3.4".to_string(),
+                    },
+                    status: NotificationStatus::Failed,
+                },
+            )
+            .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::GetFailedNotifications {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let response: crate::msg::GetFailedNotificationsResponse = from_binary(&bin).unwrap();
+        assert_eq!(response.notifications.len(), 1);
+
+        try_resend_notification(deps.as_mut(), 1).unwrap();
+        assert_eq!(
+            NOTIFICATIONS.get(deps.as_ref().storage, &1).unwrap().status,
+            NotificationStatus::Pending
+        );
+
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: 1,
+                result: cosmwasm_std::SubMsgResult::Ok(cosmwasm_std::SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            NOTIFICATIONS.get(deps.as_ref().storage, &1).unwrap().status,
+            NotificationStatus::Delivered
+        );
+
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: 1,
+                result: cosmwasm_std::SubMsgResult::Err("delivery failed".to_string()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            NOTIFICATIONS.get(deps.as_ref().storage, &1).unwrap().status,
+            NotificationStatus::Failed
+        );
+    }
+
+    #[test]
+    /// A validly quorum-signed `SubmitWorkerVAA` actually applies the action
+    /// it encodes, not just rejects replays (see `vaa_replay_is_rejected`).
+    fn submit_worker_vaa_applies_remove_worker_action() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let admin = mock_info("admin", &[]);
+        instantiate(deps.as_mut(), env, admin, InstantiateMsg {}).unwrap();
+
+        let (signing_key, pubkey) = keypair(1);
+        seed_authority(deps.as_mut(), vec![pubkey], 1);
+
+        WORKERS
+            .insert(
+                deps.as_mut().storage,
+                &"9.9.9.9".to_string(),
+                &Worker {
+                    ip_address: "9.9.9.9".to_string(),
+                    payment_wallet: vec![],
+                    attestation_report: vec![],
+                    worker_type: WorkerType::Gpu,
+                    measurement: vec![],
+                    reported_pubkey: vec![],
+                    last_liveliness: 0,
+                    last_nonce: String::new(),
+                },
+            )
+            .unwrap();
+
+        let action = WorkerVaaAction::RemoveWorker {
+            ip_address: "9.9.9.9".to_string(),
+        };
+        let emitter_chain: u16 = 7;
+        let sequence: u64 = 1;
+        let canonical_action = serde_json::to_vec(&action).unwrap();
+        let signed_message = length_prefixed_message(&[
+            &emitter_chain.to_be_bytes(),
+            &sequence.to_be_bytes(),
+            &canonical_action,
+        ]);
+        let message_hash = Sha256::digest(&signed_message);
+        let (signature, recovery_id) = sign_recoverable(&signing_key, &message_hash);
+
+        let vaa = WorkerVaa {
+            emitter_chain,
+            sequence,
+            action,
+            signatures: vec![GuardianSignature {
+                signer_index: 0,
+                signature,
+                recovery_id,
+            }],
+        };
+
+        try_submit_worker_vaa(deps.as_mut(), serde_json::to_vec(&vaa).unwrap()).unwrap();
+
+        assert!(!WORKERS.contains(deps.as_ref().storage, &"9.9.9.9".to_string()));
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A byte string that accepts any of standard base64, URL-safe base64,
+/// unpadded base64, or hex on the way in, and always re-serializes as
+/// URL-safe, unpadded base64. Used for every cryptographic field
+/// (attestation reports, wallets, public keys, signatures) so clients
+/// aren't locked into guessing one exact encoding.
+///
+/// Decoding tries each encoding in a fixed order (standard base64, URL-safe
+/// base64, unpadded variants of both, then hex) and returns the first that
+/// parses. Precedence matters: many valid hex strings (e.g. `"deadbeef"`)
+/// are also syntactically valid base64, and will silently decode as base64
+/// rather than hex, producing different bytes than a caller who meant hex
+/// intended. Integrators who need hex on a security-sensitive field should
+/// disambiguate with an out-of-band convention (e.g. a `0x` prefix handled
+/// before it reaches this type) rather than relying on this type to guess.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct EncodedBytes(pub Vec<u8>);
+
+impl EncodedBytes {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+
+    fn decode(raw: &str) -> Result<Vec<u8>, String> {
+        if let Ok(bytes) = base64::decode(raw) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = base64::decode_config(raw, base64::URL_SAFE) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = base64::decode_config(raw, base64::STANDARD_NO_PAD) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = base64::decode_config(raw, base64::URL_SAFE_NO_PAD) {
+            return Ok(bytes);
+        }
+        if let Ok(bytes) = hex::decode(raw) {
+            return Ok(bytes);
+        }
+        Err(format!(
+            "could not decode '{}' as base64 (standard, URL-safe, or no-pad) or hex",
+            raw
+        ))
+    }
+}
+
+impl Serialize for EncodedBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let canonical = base64::encode_config(&self.0, base64::URL_SAFE_NO_PAD);
+        serializer.serialize_str(&canonical)
+    }
+}
+
+impl<'de> Deserialize<'de> for EncodedBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct EncodedBytesVisitor;
+
+        impl<'de> Visitor<'de> for EncodedBytesVisitor {
+            type Value = EncodedBytes;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a base64 (standard, URL-safe, or no-pad) or hex string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                EncodedBytes::decode(value)
+                    .map(EncodedBytes)
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(EncodedBytesVisitor)
+    }
+}
+
+impl JsonSchema for EncodedBytes {
+    fn schema_name() -> String {
+        "EncodedBytes".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+impl From<Vec<u8>> for EncodedBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        EncodedBytes(bytes)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod contract;
+pub mod encoding;
+pub mod msg;
+pub mod state;
+
+pub use crate::contract::{execute, instantiate, migrate, query, reply};
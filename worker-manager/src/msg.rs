@@ -1,7 +1,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{Worker, WorkerType};
+use crate::encoding::EncodedBytes;
+use crate::state::{AuthorityPubkey, Notification, Worker, WorkerType};
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {}
@@ -11,13 +12,13 @@ pub struct InstantiateMsg {}
 pub enum ExecuteMsg {
     RegisterWorker {
         ip_address: String,
-        payment_wallet: String,
-        attestation_report: String,
+        payment_wallet: EncodedBytes,
+        attestation_report: EncodedBytes,
         worker_type: WorkerType,
     },
     SetWorkerWallet {
         ip_address: String,
-        payment_wallet: String,
+        payment_wallet: EncodedBytes,
     },
     SetWorkerAddress {
         new_ip_address: String,
@@ -30,28 +31,148 @@ pub enum ExecuteMsg {
     RemoveWorker {
         ip_address: String,
     },
-    ReportLiveliness {},
+    ReportLiveliness {
+        ip_address: String,
+        nonce: String,
+        signature: EncodedBytes,
+    },
     ReportWork {},
+    /// Admin-only: rotate the trusted attestation authority set, mirroring
+    /// Wormhole's guardian-set updates. The new index must be exactly one
+    /// greater than the currently stored index.
+    SetAttestationAuthoritySet {
+        index: u32,
+        signers: Vec<AuthorityPubkey>,
+        quorum: u32,
+    },
+    /// Admin-only: add an enclave measurement to the registration allow-list.
+    AllowMeasurement {
+        measurement: EncodedBytes,
+    },
+    /// Admin-only: add a subscriber's secp256k1 public key to the allow-list
+    /// checked by `QueryMsg::GetWorkers`.
+    AllowSubscriber {
+        subscriber_public_key: EncodedBytes,
+    },
+    /// Registers a subscriber contract address to receive push
+    /// notifications on worker registration, removal, and liveliness
+    /// transitions. `callback_code_hash` is required to route the outbound
+    /// `WasmMsg::Execute` on secret-cosmwasm-std.
+    RegisterSubscriberCallback {
+        callback_address: String,
+        callback_code_hash: String,
+    },
+    /// Resends every notification currently marked `failed`.
+    ResendNotifications {},
+    /// Resends a single notification by id, regardless of its status.
+    ResendNotification {
+        id: u64,
+    },
+    /// Ingests a signed cross-chain payload (quorum-signed by the same
+    /// attestation authority set) and applies the `RegisterWorker`/
+    /// `RemoveWorker` action it encodes, mirroring a Wormhole VAA.
+    SubmitWorkerVAA {
+        vaa: EncodedBytes,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
+    /// Restricted to allow-listed subscribers (see `AllowSubscriber`):
+    /// `signature` must be a valid secp256k1 signature by
+    /// `subscriber_public_key` over `sha256("get_workers:{epoch}")`, where
+    /// `epoch` is the current block time divided by
+    /// `LIVELINESS_EPOCH_SECONDS`, mirroring the liveliness challenge's
+    /// epoch-bucketing so no prior round trip is needed to learn a nonce.
     GetWorkers {
-        signature: String,
-        subscriber_public_key: String,
+        signature: EncodedBytes,
+        subscriber_public_key: EncodedBytes,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        filter: Option<WorkerFilter>,
     },
-    GetLivelinessChallenge {},
+    GetLivelinessChallenge {
+        ip_address: String,
+    },
+    /// Returns the last time `ip_address` completed the liveliness
+    /// challenge-response protocol, plus whether that proof is still fresh.
+    GetWorkerLiveliness {
+        ip_address: String,
+    },
+    /// Decodes and validates an attestation report against the current
+    /// authority set and measurement allow-list without mutating state,
+    /// mirroring Wormhole's `VerifyVAA`. `ip_address` must be the same
+    /// address the report would be (or was) registered under, since it's
+    /// part of the guardian-signed message.
+    VerifyAttestation {
+        report: EncodedBytes,
+        ip_address: String,
+    },
+    /// Lists notifications currently in the `failed` status so operators can
+    /// inspect the resend backlog.
+    GetFailedNotifications {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the current authority set index and the highest consumed
+    /// VAA sequence per emitter chain, so relayers know where to resume.
+    GetState {},
+}
+
+/// Server-side filter for `QueryMsg::GetWorkers`. Both fields are optional
+/// and combine with AND semantics.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct WorkerFilter {
+    pub worker_type: Option<WorkerType>,
+    /// Only include workers whose last liveliness proof is within this many
+    /// seconds of the current block time.
+    pub max_liveliness_age: Option<u64>,
 }
 
 // We define a custom struct for each query response
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct GetWorkersResponse {
     pub workers: Vec<Worker>,
+    pub next_key: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
-pub struct GetLivelinessChallengeResponse {}
+pub struct GetLivelinessChallengeResponse {
+    pub nonce: String,
+    pub expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct GetWorkerLivelinessResponse {
+    pub last_liveliness: u64,
+    pub is_live: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetFailedNotificationsResponse {
+    pub notifications: Vec<Notification>,
+    pub next_key: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct EmitterSequence {
+    pub emitter_chain: u16,
+    pub highest_sequence: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct GetStateResponse {
+    pub authority_set_index: u32,
+    pub highest_consumed_sequence: Vec<EmitterSequence>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct VerifyAttestationResponse {
+    pub measurement: Vec<u8>,
+    pub reported_pubkey: Vec<u8>,
+    pub valid: bool,
+}
 
 // We define a custom struct for each query response
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
@@ -76,4 +197,10 @@ pub struct SubscriberStatus {
 pub enum MigrateMsg {
     Migrate {},
     StdError {},
+    /// Seeds the initial `AttestationAuthoritySet` on upgrade. Only valid
+    /// when no authority set has been stored yet.
+    SeedAuthoritySet {
+        signers: Vec<AuthorityPubkey>,
+        quorum: u32,
+    },
 }
@@ -0,0 +1,161 @@
+use cosmwasm_std::Addr;
+use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
+use schemars::JsonSchema;
+use secret_toolkit::storage::Keymap;
+use serde::{Deserialize, Serialize};
+
+pub static CONFIG_KEY: &[u8] = b"config";
+pub static AUTHORITY_SET_KEY: &[u8] = b"authority_set";
+pub static LIVELINESS_SECRET_KEY: &[u8] = b"liveliness_secret";
+
+/// Width, in seconds, of a liveliness challenge epoch. A nonce issued by
+/// `GetLivelinessChallenge` stays valid (and reproducible) for the rest of
+/// the epoch it was derived in.
+pub const LIVELINESS_EPOCH_SECONDS: u64 = 60;
+
+/// A successful liveliness proof is considered stale once it is older than
+/// this many seconds.
+pub const LIVELINESS_STALENESS_SECONDS: u64 = 15 * 60;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct State {
+    pub admin: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerType {
+    Cpu,
+    Gpu,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Worker {
+    pub ip_address: String,
+    pub payment_wallet: Vec<u8>,
+    pub attestation_report: Vec<u8>,
+    pub worker_type: WorkerType,
+    /// Enclave measurement (MRENCLAVE) extracted from the attestation report.
+    pub measurement: Vec<u8>,
+    /// Public key the worker reported as part of its attestation.
+    pub reported_pubkey: Vec<u8>,
+    /// Unix timestamp (seconds) of the worker's last successful liveliness
+    /// proof, or 0 if it has never completed the challenge-response flow.
+    pub last_liveliness: u64,
+    /// The last nonce this worker successfully proved possession of, kept
+    /// to reject replay of the same challenge.
+    pub last_nonce: String,
+}
+
+/// A single trusted signer in an `AttestationAuthoritySet`, identified by its
+/// compressed secp256k1 public key (mirrors Wormhole's guardian set entries).
+pub type AuthorityPubkey = Vec<u8>;
+
+/// The current trust anchor used to validate attestation reports, modeled on
+/// Wormhole's guardian sets: a versioned quorum of signers that the admin can
+/// rotate via `ExecuteMsg::SetAttestationAuthoritySet`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttestationAuthoritySet {
+    pub index: u32,
+    pub signers: Vec<AuthorityPubkey>,
+    pub quorum: u32,
+}
+
+pub static WORKERS: Keymap<String, Worker> = Keymap::new(b"workers");
+
+/// Allow-listed enclave measurements, keyed by the raw measurement bytes.
+pub static ALLOWED_MEASUREMENTS: Keymap<Vec<u8>, ()> = Keymap::new(b"allowed_measurements");
+
+/// Allow-listed subscriber secp256k1 public keys authorized to call
+/// `QueryMsg::GetWorkers`, keyed by the raw compressed pubkey bytes.
+pub static ALLOWED_SUBSCRIBERS: Keymap<Vec<u8>, ()> = Keymap::new(b"allowed_subscribers");
+
+pub static NOTIFICATION_SEQ_KEY: &[u8] = b"notification_seq";
+
+/// A worker-registry event pushed out to subscriber callbacks.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    WorkerRegistered { ip_address: String },
+    WorkerRemoved { ip_address: String },
+    LivelinessChanged { ip_address: String, is_live: bool },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Notification {
+    pub id: u64,
+    pub callback_address: Addr,
+    /// Code hash of the contract at `callback_address`, required to route a
+    /// `WasmMsg::Execute` on secret-cosmwasm-std. Captured at notification
+    /// time so a resend still uses the hash that was on file when the
+    /// notification was created, even if the subscriber has since
+    /// re-registered with a different one.
+    pub callback_code_hash: String,
+    pub event: NotificationEvent,
+    pub status: NotificationStatus,
+}
+
+/// A subscriber contract registered to receive push notifications.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriberCallback {
+    pub address: Addr,
+    pub code_hash: String,
+}
+
+/// Subscriber contracts registered to receive push notifications, keyed by
+/// their callback address.
+pub static SUBSCRIBER_CALLBACKS: Keymap<String, SubscriberCallback> =
+    Keymap::new(b"subscriber_callbacks");
+
+/// All outbound notifications ever sent, keyed by their incrementing id.
+pub static NOTIFICATIONS: Keymap<u64, Notification> = Keymap::new(b"notifications");
+
+pub fn next_notification_id(storage: &mut dyn cosmwasm_std::Storage) -> cosmwasm_std::StdResult<u64> {
+    let mut seq = singleton(storage, NOTIFICATION_SEQ_KEY);
+    let id: u64 = seq.may_load()?.unwrap_or_default();
+    seq.save(&(id + 1))?;
+    Ok(id)
+}
+
+/// `(emitter_chain, sequence)` pairs already applied from a
+/// `SubmitWorkerVAA`, keyed as `"{emitter_chain}:{sequence}"` to prevent
+/// replay across chains.
+pub static CONSUMED_VAA_SEQUENCES: Keymap<String, ()> = Keymap::new(b"consumed_vaa_sequences");
+
+/// Highest VAA sequence consumed so far per emitter chain, so relayers know
+/// where to resume.
+pub static HIGHEST_VAA_SEQUENCE: Keymap<u16, u64> = Keymap::new(b"highest_vaa_sequence");
+
+pub fn config(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<State> {
+    singleton(storage, CONFIG_KEY)
+}
+
+pub fn config_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<State> {
+    singleton_read(storage, CONFIG_KEY)
+}
+
+pub fn authority_set(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<AttestationAuthoritySet> {
+    singleton(storage, AUTHORITY_SET_KEY)
+}
+
+pub fn authority_set_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<AttestationAuthoritySet> {
+    singleton_read(storage, AUTHORITY_SET_KEY)
+}
+
+/// Per-contract secret mixed into liveliness nonce derivation so challenges
+/// cannot be predicted off-chain. Seeded once at `instantiate`.
+pub fn liveliness_secret(storage: &mut dyn cosmwasm_std::Storage) -> Singleton<Vec<u8>> {
+    singleton(storage, LIVELINESS_SECRET_KEY)
+}
+
+pub fn liveliness_secret_read(storage: &dyn cosmwasm_std::Storage) -> ReadonlySingleton<Vec<u8>> {
+    singleton_read(storage, LIVELINESS_SECRET_KEY)
+}